@@ -0,0 +1,250 @@
+//
+//! The Piston frontend. Gui owns the drawing window, the OpenGL
+//! context and the background board image, and forwards key presses
+//! to a headless Game. Nothing in the rules engine depends on this
+//! module.
+//!
+
+use glutin_window::GlutinWindow;
+use piston::window::WindowSettings;
+use piston::event_loop::*;
+use piston::input::*;
+use opengl_graphics::{GlGraphics, OpenGL, Texture};
+use std::path::Path;
+
+use rust_monopoly::board::*;
+use rust_monopoly::game::*;
+use rust_monopoly::player::*;
+use rust_monopoly::property::*;
+use rust_monopoly::space::*;
+use rust_monopoly::rules::*;
+use rust_monopoly::layout::*;
+
+pub const WINDOW_WIDTH: i32 = 600;
+pub const WINDOW_HEIGHT: i32 = 600;
+
+const MORTGAGED: [f32; 4] = [0.3, 0.3, 0.3, 1.0]; // gray
+
+/// Objects that can be drawn to the screen with
+/// the Piston/OpenGL framework
+pub trait Render {
+    fn render(&self, gl: &mut GlGraphics, args: &RenderArgs);
+}
+
+pub struct Gui {
+    main_window: GlutinWindow,
+    gl: GlGraphics,
+    image: Texture,
+    game: Game,
+}
+
+impl Gui {
+    pub fn new() -> Gui {
+        let opengl = OpenGL::V3_2;
+        let window: GlutinWindow = WindowSettings::new(
+            "Rust Monopoly",
+            [WINDOW_WIDTH as u32, WINDOW_HEIGHT as u32]
+        )
+        .opengl(opengl)
+        .exit_on_esc(true)
+        .build()
+        .unwrap();
+        Gui {
+            main_window: window,
+            gl: GlGraphics::new(opengl),
+            image: Texture::from_path(Path::new("res/board.png"))
+                .expect("Could not load res/board.png; run the game \
+                         from the project root"),
+            game: Game::new(),
+        }
+    }
+
+    /// Play with these rules instead of the standard ones
+    pub fn set_rules(&mut self, rules: RuleSet) {
+        self.game.set_rules(rules);
+    }
+
+    /// Play on this board instead of the standard one
+    pub fn set_layout(&mut self, layout: BoardLayout) {
+        self.game.set_layout(layout);
+    }
+
+    /// Pay the cheaper income tax for players instead of asking them
+    pub fn set_auto_income_tax(&mut self, auto_income_tax: bool) {
+        self.game.set_auto_income_tax(auto_income_tax);
+    }
+
+    /// Audit the board after every turn
+    pub fn set_debug(&mut self, debug: bool) {
+        self.game.set_debug(debug);
+    }
+
+    /// Replay the game played with this seed
+    pub fn set_seed(&mut self, seed: usize) {
+        self.game.set_seed(Some(seed));
+    }
+
+    /// Clear the screen
+    fn clear(&mut self, args: &RenderArgs) {
+        use graphics::*;
+        let ref mut gl = self.gl;
+        const WHITE: [f32; 4] = [1.0; 4];
+
+        gl.draw(args.viewport(), |c, gl| {
+            clear(WHITE, gl);
+        });
+    }
+
+    /// Draw the board image, then the houses, hotels and tokens on it
+    fn draw(&mut self, args: &RenderArgs) {
+        use graphics::*;
+        let image_ref = &self.image;
+
+        self.gl.draw(args.viewport(), |c, gl| {
+            let transform = c.transform.trans(0.0, 0.0);
+            image(image_ref, transform, gl);
+        });
+        self.game.get_board().render(&mut self.gl, args);
+    }
+
+    /// The main event loop
+    pub fn run(&mut self) {
+        let mut events = self.main_window.events();
+        while let Some(e) = events.next(&mut self.main_window) {
+            self.game.update();
+            if self.game.get_game_state() == GameState::GameQuit {
+                break;
+            }
+
+            if let Some(r) = e.render_args() {
+                self.clear(&r);
+                if self.game.get_game_state() == GameState::GameGUISetup {
+                    self.game.begin_setup();
+                } else {
+                    self.draw(&r);
+                }
+            }
+
+            if let Some(Button::Keyboard(key)) = e.press_args() {
+                if let Some(c) = key_to_char(key) {
+                    self.game.handle_key_input(c);
+                }
+            };
+        }
+    }
+}
+
+impl Render for Board {
+    fn render(&self, gl: &mut GlGraphics, args: &RenderArgs) {
+        for space in self.get_spaces() {
+            space.borrow().render(gl, args);
+        }
+    }
+}
+
+impl Render for Space {
+    fn render(&self, gl: &mut GlGraphics, args: &RenderArgs) {
+        use graphics::*;
+
+        let house_width: f64 = 5.0;
+        let house_height: f64 = 5.0;
+        let hotel_width: f64 = 10.0;
+        let hotel_height: f64 = 5.0;
+        let x = self.get_x() as f64;
+        let y = self.get_y() as f64;
+
+        let mut offset: f64 = 0.0;
+        if let SpaceEnum::Prop(prop) = self.get_type().clone() {
+            let num_houses = prop.borrow().get_num_houses();
+            let num_hotels = prop.borrow().get_num_hotels();
+
+            // mortgaged properties have no buildings, so mark them in
+            // the same row
+            if prop.borrow().is_mortgaged() {
+                let mark: [graphics::types::Scalar; 4] =
+                    [x, y, hotel_width * 2.0, house_height];
+                gl.draw(args.viewport(), |c, gl| {
+                    rectangle(MORTGAGED, mark, c.transform, gl);
+                });
+            }
+
+            // houses and hotels are mutually exclusive
+            for _ in 0..num_houses {
+                let house: [graphics::types::Scalar; 4] =
+                    [x, y, house_width, house_height];
+                gl.draw(args.viewport(), |c, gl| {
+                    let transform = c.transform.trans(offset as f64, 0 as f64);
+                    rectangle(GREEN, house, transform, gl);
+                });
+                offset += house_width + 1.0; // border between houses
+            }
+            for _ in 0..num_hotels {
+                let hotel: [graphics::types::Scalar; 4] =
+                    [x, y, hotel_width, hotel_height];
+                gl.draw(args.viewport(), |c, gl| {
+                    let transform = c.transform.trans(offset as f64, 0 as f64);
+                    rectangle(RED, hotel, transform, gl);
+                });
+                offset = offset + hotel_width + 1.0;
+            }
+        }
+
+        offset = 0.0;
+        for player in self.get_players() {
+            let player = player.borrow();
+            let token = rectangle::square(x, y, PLAYER_WIDTH as f64);
+
+            gl.draw(args.viewport(), |c, gl| {
+                let transform = c.transform.trans(0 as f64,
+                                                  house_height + 1 as f64 + offset as f64);
+                rectangle(player.get_token_color(), token, transform, gl);
+            });
+            offset += PLAYER_WIDTH as f64;
+        }
+    }
+}
+
+impl Render for Property {
+    fn render(&self, gl: &mut GlGraphics, args: &RenderArgs) {
+        println!("Drew a property");
+    }
+}
+
+/// Translate a Piston key into the character the Game understands
+fn key_to_char(key: Key) -> Option<char> {
+    match key {
+        Key::A => Some('a'),
+        Key::B => Some('b'),
+        Key::C => Some('c'),
+        Key::D => Some('d'),
+        Key::F => Some('f'),
+        Key::G => Some('g'),
+        Key::H => Some('h'),
+        Key::J => Some('j'),
+        Key::K => Some('k'),
+        Key::L => Some('l'),
+        Key::M => Some('m'),
+        Key::N => Some('n'),
+        Key::O => Some('o'),
+        Key::P => Some('p'),
+        Key::Q => Some('q'),
+        Key::R => Some('r'),
+        Key::S => Some('s'),
+        Key::T => Some('t'),
+        Key::U => Some('u'),
+        Key::W => Some('w'),
+        Key::Y => Some('y'),
+        Key::D0 |
+        Key::D1 |
+        Key::D2 |
+        Key::D3 |
+        Key::D4 |
+        Key::D5 |
+        Key::D6 |
+        Key::D7 |
+        Key::D8 |
+        Key::D9   => Some(key as u8 as char),
+        Key::Return => Some('\n'),
+        _ => None,
+    }
+}
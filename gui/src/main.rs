@@ -0,0 +1,62 @@
+//
+//! CIS 198 - Rust Programming
+//! Author: Jack Harkins
+//! Project Name: Rust-Monopoly
+//!
+
+extern crate rust_monopoly;
+extern crate piston;
+extern crate graphics;
+extern crate glutin_window;
+extern crate opengl_graphics;
+
+mod gui;
+
+use std::env;
+use std::path::Path;
+use std::process;
+use gui::Gui;
+use rust_monopoly::rules::RuleSet;
+use rust_monopoly::layout::BoardLayout;
+
+const RULES_FILE: &'static str = "rules.cfg";
+
+/// The main entry point for the application. The optional arguments
+/// are the seed of a game to replay, `--board <file>` to play on
+/// another board, `--auto-income-tax` to always pay the cheaper income
+/// tax without asking and `--debug` to audit the board after every turn.
+/// The rules are read from rules.cfg if it exists.
+fn main() {
+    let mut gui = Gui::new();
+    if Path::new(RULES_FILE).exists() {
+        match RuleSet::from_file(Path::new(RULES_FILE)) {
+            Ok(rules) => gui.set_rules(rules),
+            Err(e) => exit_with(e),
+        }
+    }
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--board" {
+            let path = match args.next() {
+                Some(path) => path,
+                None => exit_with("--board needs a board file".to_string()),
+            };
+            match BoardLayout::from_file(Path::new(&path)) {
+                Ok(layout) => gui.set_layout(layout),
+                Err(e) => exit_with(e),
+            }
+        } else if arg == "--auto-income-tax" {
+            gui.set_auto_income_tax(true);
+        } else if arg == "--debug" {
+            gui.set_debug(true);
+        } else if let Ok(seed) = arg.parse() {
+            gui.set_seed(seed);
+        }
+    }
+    gui.run();
+}
+
+fn exit_with(error: String) -> ! {
+    println!("{}", error);
+    process::exit(1);
+}
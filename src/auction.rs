@@ -0,0 +1,176 @@
+//
+//! An Auction is held whenever a player lands on an unowned property
+//! and either declines to buy it or cannot afford it. Every player
+//! still in the game may bid, starting with the player who landed
+//! there and going around the table. Each bid must beat the high bid
+//! by at least MIN_BID_INCREMENT. A player who passes drops out of the
+//! auction, and it ends when only the high bidder is left (or when
+//! everyone has passed without bidding).
+//!
+//! When the bank runs short of houses and several players could build,
+//! the bank auctions its houses one at a time in the same way.
+//!
+//! The Auction only keeps track of the bidding; the Board hands the
+//! property (or house) to the winner once the auction is over.
+//!
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use super::player::*;
+use super::property::*;
+
+pub const MIN_BID_INCREMENT: i32 = 10;
+
+/// What is up for auction
+#[derive(Debug, Clone)]
+pub enum Lot {
+    Deed(Rc<RefCell<Property>>),
+    House(Rc<RefCell<Property>>), // one of the bank's last houses, for the
+                                  // property the builder asked for
+}
+
+#[derive(Debug)]
+pub struct Auction {
+    lot: Lot,
+    bidders: Vec<Rc<RefCell<Player>>>, // players who have not passed,
+                                       // in bidding order
+    bidder_turn: usize, // index into bidders
+    high_bid: i32,
+    high_bidder: Option<Rc<RefCell<Player>>>,
+}
+
+impl Auction {
+    /// An auction for the lot among the given players, the first of
+    /// whom bids first
+    pub fn new(lot: Lot, bidders: Vec<Rc<RefCell<Player>>>) -> Auction {
+        Auction {
+            lot: lot,
+            bidders: bidders,
+            bidder_turn: 0,
+            high_bid: 0,
+            high_bidder: None,
+        }
+    }
+
+    pub fn get_lot(&self) -> &Lot {
+        &self.lot
+    }
+
+    /// What is up for auction, as shown to the players
+    pub fn get_lot_name(&self) -> String {
+        match self.lot {
+            Lot::Deed(ref property) => property.borrow().get_name(),
+            Lot::House(_) => "a house".to_string(),
+        }
+    }
+
+    pub fn get_bidders(&self) -> &Vec<Rc<RefCell<Player>>> {
+        &self.bidders
+    }
+
+    pub fn get_high_bid(&self) -> i32 {
+        self.high_bid
+    }
+
+    pub fn get_high_bidder(&self) -> Option<Rc<RefCell<Player>>> {
+        self.high_bidder.clone()
+    }
+
+    /// The smallest bid the current bidder may make
+    pub fn get_min_bid(&self) -> i32 {
+        self.high_bid + MIN_BID_INCREMENT
+    }
+
+    /// The player whose turn it is to bid or pass, if the auction is
+    /// still going
+    pub fn get_current_bidder(&self) -> Option<Rc<RefCell<Player>>> {
+        if self.is_over() {
+            None
+        } else {
+            Some(self.bidders[self.bidder_turn].clone())
+        }
+    }
+
+    /// The current bidder bids the given amount. Returns false (and the
+    /// bidder keeps their turn) if the bid is too low or they cannot
+    /// pay it.
+    pub fn bid(&mut self, amount: i32) -> bool {
+        let bidder = match self.get_current_bidder() {
+            Some(bidder) => bidder,
+            None => return false,
+        };
+        if amount < self.get_min_bid() {
+            println!("The bid must be at least ${}!", self.get_min_bid());
+            return false;
+        }
+        if amount > bidder.borrow().get_cash() {
+            println!("{} only has ${}!",
+                     bidder.borrow().get_name(),
+                     bidder.borrow().get_cash());
+            return false;
+        }
+        println!("{} bids ${} for {}.",
+                 bidder.borrow().get_name(),
+                 amount,
+                 self.get_lot_name());
+        self.high_bid = amount;
+        self.high_bidder = Some(bidder);
+        self.next_bidder();
+        true
+    }
+
+    /// The current bidder drops out of the auction
+    pub fn pass(&mut self) {
+        let bidder = match self.get_current_bidder() {
+            Some(bidder) => bidder,
+            None => return,
+        };
+        println!("{} passes.", bidder.borrow().get_name());
+        self.bidders.remove(self.bidder_turn);
+        if self.bidder_turn >= self.bidders.len() {
+            self.bidder_turn = 0;
+        }
+        if self.is_high_bidder(self.bidder_turn) {
+            self.next_bidder();
+        }
+    }
+
+    /// True once every player but the high bidder has passed, or
+    /// everyone has passed without bidding
+    pub fn is_over(&self) -> bool {
+        match self.bidders.len() {
+            0 => true,
+            1 => self.is_high_bidder(0),
+            _ => false,
+        }
+    }
+
+    /// The winner and their winning bid, once the auction is over
+    pub fn get_winner(&self) -> Option<(Rc<RefCell<Player>>, i32)> {
+        if !self.is_over() {
+            return None;
+        }
+        self.high_bidder.as_ref().map(|winner| (winner.clone(), self.high_bid))
+    }
+
+    /// Move on to the next player still bidding; the high bidder is
+    /// skipped, since they cannot outbid themselves
+    fn next_bidder(&mut self) {
+        if self.bidders.len() < 2 {
+            return;
+        }
+        self.bidder_turn = (self.bidder_turn + 1) % self.bidders.len();
+        if self.is_high_bidder(self.bidder_turn) {
+            self.bidder_turn = (self.bidder_turn + 1) % self.bidders.len();
+        }
+    }
+
+    fn is_high_bidder(&self, index: usize) -> bool {
+        match self.high_bidder {
+            Some(ref high_bidder) => index < self.bidders.len() &&
+                Rc::ptr_eq(high_bidder, &self.bidders[index]),
+            None => false,
+        }
+    }
+}
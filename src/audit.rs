@@ -0,0 +1,373 @@
+//
+//! The auditor checks that the state of a game hangs together: that
+//! the Player and Property sides of every deed agree, that buildings
+//! follow the building rules, that every token is on exactly one
+//! space, and that the money in the game matches the bank's ledger.
+//!
+//! Each problem found is described in a sentence. A game played
+//! through the Board and Bank should never have any, so the auditor
+//! is a debugging aid: it runs after every turn in debug mode, and
+//! players can run it at any time.
+//!
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use super::board::*;
+use super::property::*;
+use super::space::*;
+use super::ledger::*;
+
+/// Every problem with the state of the board, described in a sentence;
+/// empty if there are none
+pub fn audit(board: &Board) -> Vec<String> {
+    let mut problems = Vec::new();
+    check_ownership(board, &mut problems);
+    check_buildings(board, &mut problems);
+    check_positions(board, &mut problems);
+    check_money(board, &mut problems);
+    problems
+}
+
+/// Audit the board and print what was found
+pub fn print_audit(board: &Board) {
+    let problems = audit(board);
+    if problems.is_empty() {
+        println!("Audit on turn {}: no problems found.", board.get_turn());
+    } else {
+        println!("Audit on turn {}: {} problem(s) found!", board.get_turn(), problems.len());
+        for problem in problems {
+            println!("  - {}", problem);
+        }
+    }
+}
+
+/// Every deed is either held by the bank and owned by no one, or held
+/// by exactly the player who owns it
+fn check_ownership(board: &Board, problems: &mut Vec<String>) {
+    let deeds = board.get_bank().get_deeds();
+    for prop in get_properties(board) {
+        let name = prop.borrow().get_name();
+        let in_bank = deeds.iter().any(|deed| Rc::ptr_eq(deed, &prop));
+        if !prop.borrow().is_owned() {
+            if !in_bank {
+                problems.push(format!("{} has no owner, but the bank does not hold \
+                                       its deed.", name));
+            }
+            if prop.borrow().is_mortgaged() {
+                problems.push(format!("{} is mortgaged, but has no owner.", name));
+            }
+            continue;
+        }
+        let owner = prop.borrow().get_owner().clone();
+        let owner_name = owner.borrow().get_name();
+        if in_bank {
+            problems.push(format!("{} is owned by {}, but the bank also holds its \
+                                   deed.", name, owner_name));
+        }
+        if !owner.borrow().get_properties().iter().any(|p| Rc::ptr_eq(p, &prop)) {
+            problems.push(format!("{} is owned by {}, but is not among {}'s \
+                                   properties.", name, owner_name, owner_name));
+        }
+        if !board.get_players().iter().any(|player| Rc::ptr_eq(player, &owner)) {
+            problems.push(format!("{} is owned by {}, who is not in the game.",
+                                  name, owner_name));
+        } else if owner.borrow().is_bankrupt() {
+            problems.push(format!("{} is owned by {}, who is bankrupt.",
+                                  name, owner_name));
+        }
+    }
+    for deed in deeds {
+        if deed.borrow().is_owned() {
+            // reported above
+            continue;
+        }
+        if !get_properties(board).iter().any(|prop| Rc::ptr_eq(prop, deed)) {
+            problems.push(format!("The bank holds the deed to {}, which is not on \
+                                   the board.", deed.borrow().get_name()));
+        }
+    }
+    for player in board.get_players() {
+        let player_name = player.borrow().get_name();
+        let properties = player.borrow().get_properties().clone();
+        for (i, prop) in properties.iter().enumerate() {
+            let name = prop.borrow().get_name();
+            if properties[..i].iter().any(|other| Rc::ptr_eq(other, prop)) {
+                problems.push(format!("{} holds {} more than once.", player_name, name));
+                continue;
+            }
+            if !prop.borrow().is_owned() {
+                problems.push(format!("{} holds {}, but {} has no owner.",
+                                      player_name, name, name));
+            } else if !Rc::ptr_eq(prop.borrow().get_owner(), player) {
+                problems.push(format!("{} holds {}, but {}'s owner is {}.",
+                                      player_name, name, name,
+                                      prop.borrow().get_owner().borrow().get_name()));
+            }
+        }
+    }
+}
+
+/// Buildings only sit on unmortgaged monopolies, evenly if the rules
+/// say so, and every house and hotel is either on the board or in the
+/// bank
+fn check_buildings(board: &Board, problems: &mut Vec<String>) {
+    let mut num_houses = 0;
+    let mut num_hotels = 0;
+    let mut color_groups: Vec<ColorGroup> = Vec::new();
+    for prop in get_properties(board) {
+        let prop = prop.borrow();
+        let name = prop.get_name();
+        let color_group = prop.get_color_group();
+        num_houses += prop.get_num_houses();
+        num_hotels += prop.get_num_hotels();
+        if !color_groups.contains(&color_group) {
+            color_groups.push(color_group.clone());
+        }
+        if prop.get_num_houses() < 0 || prop.get_num_hotels() < 0 ||
+           prop.get_num_houses() > MAX_NUM_HOUSES ||
+           prop.get_num_hotels() > MAX_NUM_HOTELS ||
+           (prop.has_hotel() && prop.has_houses()) {
+            problems.push(format!("{} has {} houses and {} hotels, which is not a \
+                                   valid number of buildings.", name,
+                                  prop.get_num_houses(), prop.get_num_hotels()));
+        }
+        if prop.get_building_level() == 0 {
+            continue;
+        }
+        if color_group == ColorGroup::Railroad || color_group == ColorGroup::Utility {
+            problems.push(format!("{} has {}, but railroads and utilities cannot be \
+                                   built on.", name, describe_buildings(&prop)));
+        } else if !prop.is_owned() {
+            problems.push(format!("{} has {}, but no owner.", name, describe_buildings(&prop)));
+        } else if !board.has_monopoly(prop.get_owner(), &color_group) {
+            problems.push(format!("{} has {}, but {} does not own all of {:?}.",
+                                  name, describe_buildings(&prop),
+                                  prop.get_owner().borrow().get_name(), color_group));
+        }
+        if board.group_has_mortgage(&color_group) {
+            problems.push(format!("{} has {}, but a property in {:?} is mortgaged.",
+                                  name, describe_buildings(&prop), color_group));
+        }
+    }
+    if board.get_rules().has_even_building() {
+        for color_group in color_groups {
+            let group = board.get_group(&color_group);
+            let highest = group.iter().max_by_key(|prop| prop.borrow().get_building_level());
+            let lowest = group.iter().min_by_key(|prop| prop.borrow().get_building_level());
+            if let (Some(highest), Some(lowest)) = (highest, lowest) {
+                let (highest, lowest) = (highest.borrow(), lowest.borrow());
+                if highest.get_building_level() - lowest.get_building_level() > 1 {
+                    problems.push(format!("{:?} is built unevenly: {} has {} but {} has {}.",
+                                          color_group,
+                                          highest.get_name(), describe_buildings(&highest),
+                                          lowest.get_name(), describe_buildings(&lowest)));
+                }
+            }
+        }
+    }
+    let bank = board.get_bank();
+    if num_houses + bank.get_houses() != NUM_BANK_HOUSES {
+        problems.push(format!("There are {} houses on the board and {} in the bank, \
+                               but the game has {}.", num_houses, bank.get_houses(),
+                              NUM_BANK_HOUSES));
+    }
+    if num_hotels + bank.get_hotels() != NUM_BANK_HOTELS {
+        problems.push(format!("There are {} hotels on the board and {} in the bank, \
+                               but the game has {}.", num_hotels, bank.get_hotels(),
+                              NUM_BANK_HOTELS));
+    }
+}
+
+/// Every player is on exactly one space, the one they think they are
+/// on, and only players in the game are on the board
+fn check_positions(board: &Board, problems: &mut Vec<String>) {
+    for player in board.get_players() {
+        let player_name = player.borrow().get_name();
+        let spaces: Vec<&Rc<RefCell<Space>>> = board.get_spaces().iter()
+            .filter(|space| space.borrow().get_players().iter()
+                    .any(|other| Rc::ptr_eq(other, player)))
+            .collect();
+        match spaces.len() {
+            0 => problems.push(format!("{} is not on any space.", player_name)),
+            1 => {
+                let space = player.borrow().get_space();
+                if !Rc::ptr_eq(spaces[0], &space) {
+                    problems.push(format!("{} is on {}, but thinks they are on {}.",
+                                          player_name, spaces[0].borrow().get_name(),
+                                          space.borrow().get_name()));
+                }
+            },
+            n => {
+                let names: Vec<String> = spaces.iter()
+                    .map(|space| space.borrow().get_name())
+                    .collect();
+                problems.push(format!("{} is on {} spaces at once: {}.",
+                                      player_name, n, names.join(", ")));
+            },
+        }
+    }
+    for space in board.get_spaces() {
+        for other in space.borrow().get_players() {
+            if !board.get_players().iter().any(|player| Rc::ptr_eq(player, other)) {
+                problems.push(format!("{} is on {}, but is not in the game.",
+                                      other.borrow().get_name(),
+                                      space.borrow().get_name()));
+            }
+        }
+    }
+}
+
+/// The bank has paid out exactly the cash the players hold, and the
+/// ledger accounts for every player's cash and every deed's owner
+fn check_money(board: &Board, problems: &mut Vec<String>) {
+    let bank = board.get_bank();
+    let ledger = bank.get_ledger();
+    let total_cash: i32 = board.get_players().iter()
+        .map(|player| player.borrow().get_cash())
+        .sum();
+    if total_cash != -bank.get_balance() {
+        problems.push(format!("The players hold ${} in total, but the bank has paid \
+                               out ${} and taken in ${}, a difference of ${}.",
+                              total_cash, bank.get_paid_out(), bank.get_paid_in(),
+                              -bank.get_balance()));
+    }
+    let mut paid_in = 0;
+    let mut paid_out = 0;
+    for t in ledger.get_transactions() {
+        if let Item::Cash(amount) = *t.get_item() {
+            if let Party::Bank = *t.get_payee() {
+                paid_in += amount;
+            }
+            if let Party::Bank = *t.get_payer() {
+                paid_out += amount;
+            }
+        }
+    }
+    if paid_in != bank.get_paid_in() || paid_out != bank.get_paid_out() {
+        problems.push(format!("The bank has taken in ${} and paid out ${}, but its \
+                               ledger records ${} and ${}.",
+                              bank.get_paid_in(), bank.get_paid_out(), paid_in, paid_out));
+    }
+    for player in board.get_players() {
+        let player = player.clone();
+        let cash = player.borrow().get_cash();
+        let recorded: i32 = ledger.get_statement(&player).iter()
+            .map(|t| t.get_cash_change(&player))
+            .sum();
+        if cash != recorded {
+            problems.push(format!("{} has ${}, but their statement adds up to ${}.",
+                                  player.borrow().get_name(), cash, recorded));
+        }
+        if cash < 0 {
+            problems.push(format!("{} has ${} in cash, which is less than nothing.",
+                                  player.borrow().get_name(), cash));
+        }
+    }
+    for prop in get_properties(board) {
+        let last = ledger.get_transactions().iter().rev()
+            .find(|t| match *t.get_item() {
+                Item::Deed(ref deed) => Rc::ptr_eq(deed, &prop),
+                Item::Cash(_) => false,
+            });
+        let recorded_owner = match last {
+            Some(t) => t.get_payee().clone(),
+            None => Party::Bank,
+        };
+        let prop = prop.borrow();
+        let agrees = match recorded_owner {
+            Party::Bank => !prop.is_owned(),
+            Party::Player(ref player) => prop.is_owned() &&
+                                         Rc::ptr_eq(player, prop.get_owner()),
+        };
+        if !agrees {
+            let owner = if prop.is_owned() {
+                prop.get_owner().borrow().get_name()
+            } else {
+                "the bank".to_string()
+            };
+            problems.push(format!("The ledger says {} last went to {}, but it belongs \
+                                   to {}.", prop.get_name(), recorded_owner.get_name(),
+                                  owner));
+        }
+    }
+}
+
+/// Every property on the board, in board order
+fn get_properties(board: &Board) -> Vec<Rc<RefCell<Property>>> {
+    board.get_spaces().iter()
+        .filter_map(|space| match *space.borrow().get_type() {
+            SpaceEnum::Prop(ref prop) => Some(prop.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn describe_buildings(prop: &Property) -> String {
+    if prop.has_hotel() {
+        "a hotel".to_string()
+    } else {
+        match prop.get_num_houses() {
+            0 => "no houses".to_string(),
+            1 => "1 house".to_string(),
+            n => format!("{} houses", n),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::game::*;
+    use super::super::player::*;
+    use super::super::cards::*;
+
+    /// Answer whatever the game is waiting for: roll, buy everything,
+    /// pay to leave jail, pass on bids, pay the flat income tax and
+    /// give up when short of money
+    fn press_key(game: &mut Game) {
+        match game.get_turn_state() {
+            TurnState::WaitingForCommand => game.handle_key_input('r'),
+            TurnState::ConfirmPurchase(_) => game.handle_key_input('y'),
+            TurnState::InJail | TurnState::EnterBid => game.handle_key_input('p'),
+            TurnState::ConfirmIncomeTax => game.handle_key_input('f'),
+            TurnState::RaiseFunds => game.handle_key_input('g'),
+            TurnState::ConfirmGiveUp => game.handle_key_input('y'),
+            _ => {},
+        }
+    }
+
+    #[test]
+    fn scripted_game_passes_the_audit() {
+        let mut game = Game::new();
+        game.set_seed(Some(7));
+        game.setup_players(vec![("A".to_string(), RED),
+                                ("B".to_string(), BLUE),
+                                ("C".to_string(), GREEN)]);
+        {
+            let random = game.get_board_mut().get_random_mut();
+            // A: Chance, on to Pennsylvania Railroad; B: Baltic Avenue;
+            // C: doubles to Income Tax, then Chance and jail; A: doubles
+            // to Kentucky Avenue, then Atlantic Avenue; B: doubles to
+            // Reading Railroad, then Electric Company
+            random.load_dice(&[3, 4, 1, 2, 2, 2, 1, 2, 3, 3, 4, 1, 1, 1, 3, 4]);
+            random.stack_chance(vec![Chance::AdvanceToNearestRailroad,
+                                     Chance::GoToJail]);
+        }
+
+        let mut num_turns = 0;
+        for _ in 0..5000 {
+            if game.get_turn_state() == TurnState::StartTurn {
+                assert_eq!(audit(game.get_board()), Vec::<String>::new());
+                num_turns += 1;
+            }
+            if game.get_game_state() != GameState::GameRun {
+                break;
+            }
+            game.update();
+            press_key(&mut game);
+        }
+        assert!(num_turns > 20);
+        assert_eq!(audit(game.get_board()), Vec::<String>::new());
+    }
+}
@@ -0,0 +1,191 @@
+//
+//! The Bank holds everything no player owns: the deeds not yet sold,
+//! the houses and hotels not yet built, and an unlimited supply of
+//! money. Every payment and every change of ownership goes through
+//! the Bank, whether or not the bank is a party to it, so that the
+//! Player and Property sides of a deed never disagree, the money
+//! paid in and out of the game can be counted, and everything that
+//! changes hands is written in the ledger.
+//!
+//! The Bank does not check the rules of the game (whether a player
+//! may build, or can afford something); the Board does that before
+//! asking the Bank to move anything.
+//!
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use super::player::*;
+use super::property::*;
+use super::ledger::*;
+
+#[derive(Debug)]
+pub struct Bank {
+    deeds: Vec<Rc<RefCell<Property>>>, // deeds no player owns
+    houses: i32,
+    hotels: i32,
+    paid_in: i32, // all the money players have paid the bank
+    paid_out: i32, // all the money the bank has paid players
+    ledger: Ledger,
+}
+
+impl Bank {
+    /// A bank with the whole building supply and no deeds
+    pub fn new() -> Bank {
+        Bank {
+            deeds: Vec::new(),
+            houses: NUM_BANK_HOUSES,
+            hotels: NUM_BANK_HOTELS,
+            paid_in: 0,
+            paid_out: 0,
+            ledger: Ledger::new(),
+        }
+    }
+
+    /// Start over with every deed on a fresh board
+    pub fn reset_deeds(&mut self, deeds: Vec<Rc<RefCell<Property>>>) {
+        self.deeds = deeds;
+    }
+
+    /// The deeds no player owns
+    pub fn get_deeds(&self) -> &Vec<Rc<RefCell<Property>>> {
+        &self.deeds
+    }
+
+    pub fn get_houses(&self) -> i32 {
+        self.houses
+    }
+
+    pub fn get_hotels(&self) -> i32 {
+        self.hotels
+    }
+
+    pub fn get_paid_in(&self) -> i32 {
+        self.paid_in
+    }
+
+    pub fn get_paid_out(&self) -> i32 {
+        self.paid_out
+    }
+
+    pub fn get_ledger(&self) -> &Ledger {
+        &self.ledger
+    }
+
+    /// Every following transaction is recorded on the next turn
+    pub fn start_turn(&mut self) {
+        self.ledger.next_turn();
+    }
+
+    /// The money the bank has taken out of the game, less what it has
+    /// put in; negative if it has put in more
+    pub fn get_balance(&self) -> i32 {
+        self.paid_in - self.paid_out
+    }
+
+    /// The payer pays the bank
+    pub fn receive(&mut self, payer: &Rc<RefCell<Player>>, amount: i32, reason: &str) {
+        payer.borrow_mut().tax(amount);
+        self.paid_in += amount;
+        self.record_cash(Party::Player(payer.clone()), Party::Bank, amount, reason);
+    }
+
+    /// The bank pays the payee
+    pub fn pay_out(&mut self, payee: &Rc<RefCell<Player>>, amount: i32, reason: &str) {
+        payee.borrow_mut().salary(amount);
+        self.paid_out += amount;
+        self.record_cash(Party::Bank, Party::Player(payee.clone()), amount, reason);
+    }
+
+    /// One player pays another
+    pub fn transfer(&mut self, payer: &Rc<RefCell<Player>>,
+                    payee: &Rc<RefCell<Player>>, amount: i32, reason: &str) {
+        payer.borrow_mut().tax(amount);
+        payee.borrow_mut().salary(amount);
+        self.record_cash(Party::Player(payer.clone()), Party::Player(payee.clone()),
+                         amount, reason);
+    }
+
+    /// The buyer pays the price and receives the bank's deed
+    pub fn sell_deed(&mut self, prop: &Rc<RefCell<Property>>,
+                     buyer: &Rc<RefCell<Player>>, price: i32, reason: &str) {
+        let name = prop.borrow().get_name();
+        self.receive(buyer, price, &format!("{} of {}", reason, name));
+        self.give_deed(prop, buyer, &format!("{} for ${}", reason, price));
+    }
+
+    /// The bank hands over one of its deeds for nothing (or for a
+    /// payment made separately)
+    pub fn give_deed(&mut self, prop: &Rc<RefCell<Property>>,
+                     owner: &Rc<RefCell<Player>>, reason: &str) {
+        self.deeds.retain(|deed| !Rc::ptr_eq(deed, prop));
+        owner.borrow_mut().add_property(prop.clone());
+        prop.borrow_mut().set_owner(Some(owner.clone()));
+        self.ledger.record(Party::Bank, Party::Player(owner.clone()),
+                           Item::Deed(prop.clone()), reason);
+    }
+
+    /// The bank takes a deed back from its owner; it comes back
+    /// without a mortgage
+    pub fn take_back_deed(&mut self, prop: &Rc<RefCell<Property>>, reason: &str) {
+        if prop.borrow().is_owned() {
+            let owner = prop.borrow().get_owner().clone();
+            owner.borrow_mut().remove_property(prop);
+            self.ledger.record(Party::Player(owner), Party::Bank,
+                               Item::Deed(prop.clone()), reason);
+        }
+        prop.borrow_mut().set_owner(None);
+        prop.borrow_mut().set_mortgaged(false);
+        self.deeds.push(prop.clone());
+    }
+
+    /// A deed changes hands from its owner to another player
+    pub fn transfer_deed(&mut self, prop: &Rc<RefCell<Property>>,
+                         new_owner: &Rc<RefCell<Player>>, reason: &str) {
+        let old_owner = if prop.borrow().is_owned() {
+            let owner = prop.borrow().get_owner().clone();
+            owner.borrow_mut().remove_property(prop);
+            Party::Player(owner)
+        } else {
+            self.deeds.retain(|deed| !Rc::ptr_eq(deed, prop));
+            Party::Bank
+        };
+        new_owner.borrow_mut().add_property(prop.clone());
+        prop.borrow_mut().set_owner(Some(new_owner.clone()));
+        self.ledger.record(old_owner, Party::Player(new_owner.clone()),
+                           Item::Deed(prop.clone()), reason);
+    }
+
+    /// Nothing is recorded when no money changes hands
+    fn record_cash(&mut self, payer: Party, payee: Party, amount: i32, reason: &str) {
+        if amount != 0 {
+            self.ledger.record(payer, payee, Item::Cash(amount), reason);
+        }
+    }
+
+    /// Houses leave the bank's supply to be built
+    pub fn take_houses(&mut self, num: i32) {
+        assert!(num <= self.houses, "The bank is out of houses!");
+        self.houses -= num;
+    }
+
+    /// Houses come back to the bank's supply
+    pub fn return_houses(&mut self, num: i32) {
+        self.houses += num;
+    }
+
+    pub fn take_hotel(&mut self) {
+        assert!(self.hotels > 0, "The bank is out of hotels!");
+        self.hotels -= 1;
+    }
+
+    pub fn return_hotel(&mut self) {
+        self.hotels += 1;
+    }
+}
+
+impl Default for Bank {
+    fn default() -> Bank {
+        Bank::new()
+    }
+}
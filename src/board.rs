@@ -1,7 +1,8 @@
 //
 //! Board keeps track of most of the game state local to the Monopoly
 //! board, including the spaces (and properties contained within them),
-//! the players, the card decks, and the background board image.
+//! the players, the card decks, and the source of randomness for
+//! dice and shuffles.
 //!
 //! Most of the methods in Board do not take in a current player
 //! argument since this is already determined by the player_turn
@@ -9,89 +10,140 @@
 //!
 
 
-extern crate rand;
-extern crate graphics;
-extern crate glutin_window;
-extern crate opengl_graphics;
-extern crate piston;
-extern crate core;
-
 use std::io::{self, Write};
-use std::collections::BTreeMap;
+use std::cmp;
 use std::rc::Rc;
 use std::cell::RefCell;
-use rand::Rng;
-use piston::input::*;
-use opengl_graphics::{GlGraphics, Texture};
-use std::path::Path;
 
 use super::player::*;
 use super::property::*;
 use super::game::*;
 use super::cards::*;
 use super::space::*;
+use super::random::*;
+use super::auction::*;
+use super::trade::*;
+use super::rules::*;
+use super::layout::*;
+use super::bank::*;
 
-const NUM_SPACES: usize = 40;
-const MAX_NUM_PLAYERS: i32 = 6;
+const MAX_NUM_DOUBLES: i32 = 3; // rolling this many doubles in a row jails
+const MAX_JAIL_TURNS: i32 = 3; // failed doubles attempts before the fine is forced
 
 pub struct Board {
     spaces: Vec<Rc<RefCell<Space>>>,
     players: Vec<Rc<RefCell<Player>>>,
     player_turn: usize, // index into playerss
-    image: Texture,
-    chance_cards: Vec<Chance>,
-    comm_chest_cards: Vec<CommunityChest>,
+    chance_cards: Deck<Chance>,
+    comm_chest_cards: Deck<CommunityChest>,
     passed_go: bool,
+    random: Random,
+    last_roll: Option<DiceRoll>, // the current player's last roll this turn
+    num_doubles: i32, // doubles rolled in a row this turn
+    auction: Option<Auction>, // the auction being held, if any
+    trade: Option<Trade>, // the trade waiting for an answer, if any
+    returned_properties: Vec<Rc<RefCell<Property>>>, // back with the bank,
+                                                     // awaiting auction
+    rules: RuleSet,
+    layout: BoardLayout,
+    jackpot: i32, // the Free Parking pot, if that house rule is on
+    bank: Bank,
 }
 
 impl Board {
     pub fn new() -> Board {
+        Board::with_random(Random::new())
+    }
+    
+    /// A board whose dice and shuffles come from the given Random
+    /// (seeded or scripted)
+    pub fn with_random(mut random: Random) -> Board {
+        let mut chance_cards = Deck::new(chance_deck());
+        let mut comm_chest_cards = Deck::new(comm_chest_deck());
+        chance_cards.shuffle(&mut random);
+        comm_chest_cards.shuffle(&mut random);
         Board {
-            spaces: Vec::with_capacity(NUM_SPACES),
+            spaces: Vec::new(),
             players: Vec::new(),
             player_turn: 0,
-            image: Texture::from_path(Path::new("res/board.png")).unwrap(),
-            chance_cards: Vec::new(),
-            comm_chest_cards: Vec::new(),
+            chance_cards: chance_cards,
+            comm_chest_cards: comm_chest_cards,
             passed_go: false,
+            random: random,
+            last_roll: None,
+            num_doubles: 0,
+            auction: None,
+            trade: None,
+            returned_properties: Vec::new(),
+            rules: RuleSet::new(),
+            layout: BoardLayout::new(),
+            jackpot: 0,
+            bank: Bank::new(),
         }
     }
     
-
+    pub fn get_rules(&self) -> &RuleSet {
+        &self.rules
+    }
     
-    pub fn shuffle_chance(&mut self) {
-        self.chance_cards = Vec::new();
-        let mut rng = rand::thread_rng();
-        let mut order_to_card: BTreeMap<i32, Chance> = BTreeMap::new();
-        
-        // Yes, this is not guaranteed to always insert every card.
-        // In real life though, we sometimes forget to shuffle a card :)
-        order_to_card.insert(rng.gen_range(1, 10000000), Chance::AdvanceToGo);
-        order_to_card.insert(rng.gen_range(1, 10000000), Chance::AdvanceToNearestUtility);
-        order_to_card.insert(rng.gen_range(1, 10000000), Chance::AdvanceToNearestRailroad);
-        order_to_card.insert(rng.gen_range(1, 10000000), Chance::GoBack3Spaces);
-        order_to_card.insert(rng.gen_range(1, 10000000), Chance::AdvanceToBoardwalk);
-        
-        for (_, card) in order_to_card {
-            self.chance_cards.push(card);
+    pub fn set_rules(&mut self, rules: RuleSet) {
+        self.rules = rules;
+        self.jackpot = self.get_jackpot_seed();
+    }
+    
+    pub fn get_layout(&self) -> &BoardLayout {
+        &self.layout
+    }
+    
+    /// Play on this board instead of the standard one; takes effect
+    /// when the spaces are next reset
+    pub fn set_layout(&mut self, layout: BoardLayout) {
+        self.layout = layout;
+    }
+    
+    /// The money in the Free Parking pot
+    pub fn get_jackpot(&self) -> i32 {
+        self.jackpot
+    }
+    
+    /// What the bank puts into the Free Parking pot each time it is
+    /// emptied (and at the start of the game)
+    fn get_jackpot_seed(&self) -> i32 {
+        if self.rules.has_free_parking_jackpot() {
+            self.rules.get_free_parking_seed()
+        } else {
+            0
         }
     }
     
+    pub fn get_bank(&self) -> &Bank {
+        &self.bank
+    }
+    
+    pub fn get_random_mut(&mut self) -> &mut Random {
+        &mut self.random
+    }
+    
+    pub fn get_seed(&self) -> usize {
+        self.random.get_seed()
+    }
+    
+    /// Shuffle every Chance card not held by a player
+    pub fn shuffle_chance(&mut self) {
+        self.chance_cards.shuffle(&mut self.random);
+    }
+    
+    /// Shuffle every Community Chest card not held by a player
     pub fn shuffle_comm_chest(&mut self) {
-        self.comm_chest_cards = Vec::new();
-        let mut rng = rand::thread_rng();
-        let mut order_to_card: BTreeMap<i32, CommunityChest> = BTreeMap::new();
-        
-        // Yes, this is not guaranteed to always insert every card.
-        // In real life though, we sometimes forget to shuffle a card :)
-        order_to_card.insert(rng.gen_range(1, 10000000), CommunityChest::AdvanceToGo);
-        order_to_card.insert(rng.gen_range(1, 10000000), CommunityChest::BankErrorInYourFavor);
-        order_to_card.insert(rng.gen_range(1, 10000000), CommunityChest::GoToJail);
-        order_to_card.insert(rng.gen_range(1, 10000000), CommunityChest::PaySchoolFees);
-        
-        for (_, card) in order_to_card {
-            self.comm_chest_cards.push(card);
-        }
+        self.comm_chest_cards.shuffle(&mut self.random);
+    }
+    
+    pub fn get_chance_cards(&self) -> &Deck<Chance> {
+        &self.chance_cards
+    }
+    
+    pub fn get_comm_chest_cards(&self) -> &Deck<CommunityChest> {
+        &self.comm_chest_cards
     }
     
     pub fn start_turn(&mut self) {
@@ -100,83 +152,980 @@ impl Board {
             self.end_turn();
             player = self.get_current_player();
         }
+        self.bank.start_turn();
 
         println!("It is {}'s turn. You have ${}.",
                  player.borrow().get_name(),
                  player.borrow().get_cash());
+        self.print_jackpot();
         
-        player.borrow_mut().set_creditor(None);
         player.borrow_mut().set_turn(true);
+        self.last_roll = None;
+        self.num_doubles = 0;
     }
     
     /// Debtor is assumed to be the current player
     pub fn on_rent_collected(&mut self, owner: Rc<RefCell<Player>>,
-                             prop: Rc<RefCell<Property>>) {
+                             prop: Rc<RefCell<Property>>,
+                             modifier: &RentModifier) {
         let debtor = self.players[self.player_turn].clone();
-        let rent = self.get_rent(prop.clone());
-        owner.borrow_mut().collect_rent(debtor.clone(), rent);
-        debtor.borrow_mut().set_creditor(Some(owner.clone()));
+        let rent = self.get_rent(prop.clone(), modifier);
+        let reason = format!("rent on {}", prop.borrow().get_name());
+        self.pay(debtor, Some(owner), rent, &reason);
+    }
+    
+    /// The payer pays the amount to the payee (None for the bank).
+    /// Whatever the payer cannot cover in cash becomes a debt to the
+    /// payee, which they have to raise funds for.
+    pub fn pay(&mut self, payer: Rc<RefCell<Player>>,
+               payee: Option<Rc<RefCell<Player>>>, amount: i32, reason: &str) {
+        self.charge(payer, payee, amount, reason, false);
+    }
+    
+    /// The payer pays a tax, fee or fine to the bank. With the Free
+    /// Parking jackpot house rule, the money goes into the pot instead,
+    /// and so does whatever is paid later on a debt for it.
+    pub fn pay_fee(&mut self, payer: Rc<RefCell<Player>>, amount: i32, reason: &str) {
+        let paid = self.charge(payer, None, amount, reason, true);
+        if self.rules.has_free_parking_jackpot() {
+            self.jackpot += paid;
+        }
+    }
+    
+    /// Pay what the payer's cash covers and turn the rest into a debt.
+    /// Returns the amount paid straight away.
+    fn charge(&mut self, payer: Rc<RefCell<Player>>, payee: Option<Rc<RefCell<Player>>>,
+              amount: i32, reason: &str, fee: bool) -> i32 {
+        let paid = cmp::min(amount, cmp::max(payer.borrow().get_cash(), 0));
+        match payee {
+            Some(ref payee) => self.bank.transfer(&payer, payee, paid, reason),
+            None => self.bank.receive(&payer, paid, reason),
+        }
+        if paid < amount {
+            println!("{} is ${} short and must raise funds!",
+                     payer.borrow().get_name(),
+                     amount - paid);
+            payer.borrow_mut().add_debt(payee, amount - paid, fee);
+        }
+        paid
     }
     
+    /// Pay as much of the player's debts as their cash allows, to each
+    /// creditor in the order the debts were run up. With the Free
+    /// Parking jackpot, debts for taxes and fees go into the pot.
+    pub fn settle_debt(&mut self, debtor: Rc<RefCell<Player>>) {
+        let mut paid = 0;
+        let debts = debtor.borrow().get_debts().clone();
+        for debt in debts {
+            let amount = cmp::min(debt.get_amount(), debtor.borrow().get_cash());
+            if amount <= 0 {
+                break;
+            }
+            let creditor = debt.get_creditor();
+            debtor.borrow_mut().reduce_debt(&debt, amount);
+            match creditor {
+                Some(ref creditor) => self.bank.transfer(&debtor, creditor, amount, "debt"),
+                None => {
+                    self.bank.receive(&debtor, amount, "debt");
+                    if debt.is_fee() && self.rules.has_free_parking_jackpot() {
+                        self.jackpot += amount;
+                    }
+                },
+            }
+            paid += amount;
+        }
+        if paid == 0 {
+            return;
+        }
+        if debtor.borrow().is_in_debt() {
+            println!("{} paid ${} and still owes ${}.",
+                     debtor.borrow().get_name(),
+                     paid,
+                     debtor.borrow().get_debt());
+        } else {
+            println!("{} paid off their debt of ${}.",
+                     debtor.borrow().get_name(),
+                     paid);
+        }
+    }
+    
+    /// The player who still has a debt to raise funds for, if any:
+    /// the current player first, then the others in turn order
+    pub fn get_debtor(&self) -> Option<Rc<RefCell<Player>>> {
+        let num_players = self.players.len();
+        (0..num_players)
+            .map(|i| self.players[(self.player_turn + i) % num_players].clone())
+            .find(|player| player.borrow().is_in_debt() && !player.borrow().is_bankrupt())
+    }
+    
+    /// The player selling buildings and mortgaging: the player raising
+    /// funds if there is one, otherwise the current player
+    pub fn get_acting_player(&self) -> Rc<RefCell<Player>> {
+        match self.get_debtor() {
+            Some(debtor) => debtor,
+            None => self.get_current_player(),
+        }
+    }
+    
+    /// Settle what debts can be paid in cash, then declare bankrupt
+    /// every player whose debt is more than they could raise by
+    /// selling buildings and mortgaging
     pub fn handle_bankruptcy(&mut self) {
-        let debtor = self.get_current_player();
-        if debtor.borrow().is_bankrupt() {
-            println!("{} is bankrupt!", debtor.borrow().get_name());
-            let creditor = {
-                let debtor = debtor.borrow();
-                debtor.get_creditor().clone()
+        for debtor in self.players.clone() {
+            if !debtor.borrow().is_in_debt() || debtor.borrow().is_bankrupt() {
+                continue;
+            }
+            self.settle_debt(debtor.clone());
+            let debt = debtor.borrow().get_debt();
+            if debt == 0 || debtor.borrow().get_liquidation_value() >= debt {
+                continue;
+            }
+            println!("{} cannot raise the ${} they owe!",
+                     debtor.borrow().get_name(), debt);
+            self.go_bankrupt(debtor);
+        }
+    }
+    
+    /// The player raising funds gives up and goes bankrupt instead,
+    /// unless selling their buildings covers what they owe. Returns
+    /// false if no one is raising funds.
+    pub fn give_up(&mut self) -> bool {
+        let debtor = match self.get_debtor() {
+            Some(debtor) => debtor,
+            None => return false,
+        };
+        println!("{} gives up!", debtor.borrow().get_name());
+        self.go_bankrupt(debtor);
+        true
+    }
+    
+    /// Sell the debtor's buildings and pay what that raises to their
+    /// creditors. If they still owe anything, share out the rest and
+    /// take them out of the game.
+    fn go_bankrupt(&mut self, debtor: Rc<RefCell<Player>>) {
+        self.sell_all_buildings(debtor.clone());
+        self.settle_debt(debtor.clone());
+        if !debtor.borrow().is_in_debt() {
+            println!("{} is out of debt and stays in the game.",
+                     debtor.borrow().get_name());
+            return;
+        }
+        println!("{} is bankrupt!", debtor.borrow().get_name());
+        self.split_assets(debtor.clone());
+        debtor.borrow_mut().declare_bankrupt();
+    }
+    
+    /// Sell every building the player owns back to the bank at half
+    /// price, as a bankrupt player must before settling with creditors
+    pub fn sell_all_buildings(&mut self, player: Rc<RefCell<Player>>) {
+        let properties = player.borrow().get_properties().clone();
+        for prop in properties {
+            let num_buildings = prop.borrow().get_building_level();
+            if num_buildings == 0 {
+                continue;
+            }
+            let value = num_buildings * (prop.borrow().get_building_cost() / 2);
+            println!("The buildings on {} were sold for ${}.",
+                     prop.borrow().get_name(), value);
+            while prop.borrow().has_hotel() {
+                prop.borrow_mut().remove_hotel();
+                self.bank.return_hotel();
+            }
+            while prop.borrow().has_houses() {
+                prop.borrow_mut().remove_house();
+                self.bank.return_houses(1);
+            }
+            let reason = format!("buildings on {}", prop.borrow().get_name());
+            self.bank.pay_out(&player, value, &reason);
+        }
+    }
+    
+    /// Share out what the bankrupt debtor has left among the creditors
+    /// they still owe. Each deed goes to whichever creditor is owed the
+    /// most at that point and counts for its mortgage value against
+    /// that debt; deeds owed to the bank go back to it. Any cash and
+    /// Get Out of Jail Free cards go to the creditor owed the most.
+    pub fn split_assets(&mut self, debtor: Rc<RefCell<Player>>) {
+        let mut debts: Vec<(Option<Rc<RefCell<Player>>>, i32)> = debtor.borrow()
+            .get_debts().iter()
+            .map(|debt| (debt.get_creditor(), debt.get_amount()))
+            .collect();
+        let main_creditor = get_largest_debt(&debts)
+            .and_then(|i| debts[i].0.clone());
+        let cash = debtor.borrow().get_cash();
+        if cash > 0 {
+            match main_creditor {
+                Some(ref creditor) => self.bank.transfer(&debtor, creditor, cash, "bankruptcy"),
+                None => self.bank.receive(&debtor, cash, "bankruptcy"),
+            }
+        }
+        let properties = debtor.borrow().get_properties().clone();
+        for property in properties {
+            let creditor = match get_largest_debt(&debts) {
+                Some(i) => {
+                    debts[i].1 -= property.borrow().get_mortgage_value();
+                    debts[i].0.clone()
+                },
+                None => main_creditor.clone(),
             };
             match creditor {
-                Some(ref creditor) => {
-                    println!("{}'s assets will be transferred to \
-                              {}, the creditor.",
-                              debtor.borrow().get_name(),
-                              creditor.borrow().get_name());
-                    self.acquire_assets(creditor.clone());
-                },
-                //TODO: return assets to bank
-                None => {
-                    println!("{}'s assets will be transferred back to the bank.",
-                             debtor.borrow().get_name());
-                    self.return_assets();
+                Some(creditor) => self.acquire_deed(property, creditor),
+                None => self.return_deed(property),
+            }
+        }
+        loop {
+            let card = debtor.borrow_mut().take_jail_card();
+            match card {
+                Some(card) => match main_creditor {
+                    Some(ref creditor) => creditor.borrow_mut().add_jail_card(card),
+                    None => self.return_jail_card(card),
                 },
+                None => break,
             }
         }
     }
     
-    pub fn acquire_assets(&mut self, creditor: Rc<RefCell<Player>>) {
-        let debtor = self.get_current_player();
-        for property in debtor.borrow().get_properties() {
-            creditor.borrow_mut().add_property(property.clone());
-            property.borrow_mut().set_owner(Some(creditor.clone()));
+    /// A creditor takes over a bankrupt player's deed. If it is
+    /// mortgaged they pay the bank 10% interest straight away.
+    fn acquire_deed(&mut self, property: Rc<RefCell<Property>>,
+                    creditor: Rc<RefCell<Player>>) {
+        println!("{} goes to {}.", property.borrow().get_name(),
+                 creditor.borrow().get_name());
+        self.bank.transfer_deed(&property, &creditor, "bankruptcy");
+        if property.borrow().is_mortgaged() {
+            let interest = property.borrow().get_unmortgage_cost() -
+                           property.borrow().get_mortgage_value();
+            println!("{} pays ${} interest on mortgaged {}.",
+                     creditor.borrow().get_name(),
+                     interest,
+                     property.borrow().get_name());
+            let reason = format!("interest on {}", property.borrow().get_name());
+            self.pay(creditor.clone(), None, interest, &reason);
         }
     }
     
     pub fn handle_pass_go(&mut self) {
         let player = self.get_current_player();
+        let salary = self.rules.get_go_salary();
         println!("{} passed GO and collected {}.",
                  player.borrow().get_name(),
-                 GO_SALARY);
-        player.borrow_mut().salary(GO_SALARY);
+                 salary);
+        self.bank.pay_out(&player, salary, "GO salary");
         self.passed_go = false;
     }
     
-    pub fn return_assets(&mut self) {
-        let debtor = self.get_current_player();
-        for property in debtor.borrow().get_properties() {
-            property.borrow_mut().set_owner(None);
+    /// The bank takes back a bankrupt player's deed. It loses any
+    /// mortgage and is auctioned off.
+    fn return_deed(&mut self, property: Rc<RefCell<Property>>) {
+        println!("{} goes back to the bank.", property.borrow().get_name());
+        self.bank.take_back_deed(&property, "bankruptcy");
+        if self.rules.has_auctions() {
+            self.returned_properties.push(property);
+        }
+    }
+    
+    /// Puts a used Get Out of Jail Free card back at the bottom of the
+    /// deck it came from
+    pub fn return_jail_card(&mut self, card: JailCard) {
+        match card {
+            JailCard::Chance =>
+                self.chance_cards.put_on_bottom(Chance::GetOutOfJailFree),
+            JailCard::CommunityChest =>
+                self.comm_chest_cards.put_on_bottom(CommunityChest::GetOutOfJailFree),
+        }
+    }
+    
+    /// The current player uses a Get Out of Jail Free card.
+    /// Returns false (and stays in jail) if they do not have one.
+    pub fn use_jail_card(&mut self) -> bool {
+        let player = self.get_current_player();
+        let card = player.borrow_mut().take_jail_card();
+        match card {
+            Some(card) => {
+                println!("{} used a Get Out of Jail Free card.",
+                         player.borrow().get_name());
+                player.borrow_mut().unjail();
+                self.return_jail_card(card);
+                true
+            },
+            None => {
+                println!("You don't have a Get Out of Jail Free card! \
+                          Choose another option.");
+                false
+            },
         }
     }
     
     pub fn on_purchase(&mut self, prop: Rc<RefCell<Property>>) {
         let buyer = self.get_current_player();
-        buyer.borrow_mut().purchase(prop.clone());
-        prop.borrow_mut().set_owner(Some(buyer.clone()));
+        let price = prop.borrow().get_purchase_price();
+        println!("{} purchased {} for ${}!",
+                 buyer.borrow().get_name(),
+                 prop.borrow().get_name(),
+                 price);
+        self.bank.sell_deed(&prop, &buyer, price, "purchase");
+    }
+    
+    /// Put an unowned property up for auction among all players still
+    /// in the game, starting with the current player
+    pub fn start_auction(&mut self, prop: Rc<RefCell<Property>>) {
+        let bidders = self.get_players_in_turn_order();
+        println!("{} is up for auction! Bids go up in steps of at least ${}.",
+                 prop.borrow().get_name(), MIN_BID_INCREMENT);
+        self.auction = Some(Auction::new(Lot::Deed(prop), bidders));
+        self.check_auction_over();
+    }
+    
+    /// During a housing shortage, the bank auctions one of its houses
+    /// to the players who could build one. The house goes on the given
+    /// property if the winner owns it.
+    fn start_house_auction(&mut self, prop: Rc<RefCell<Property>>,
+                           bidders: Vec<Rc<RefCell<Player>>>) {
+        println!("The bank has only {} houses left and {} players could build, \
+                  so it auctions a house! Bids go up in steps of at least ${}.",
+                 self.bank.get_houses(), bidders.len(), MIN_BID_INCREMENT);
+        self.auction = Some(Auction::new(Lot::House(prop), bidders));
+        self.check_auction_over();
+    }
+    
+    /// Every player still in the game, starting with the current player
+    fn get_players_in_turn_order(&self) -> Vec<Rc<RefCell<Player>>> {
+        let mut players = Vec::new();
+        for i in 0..self.players.len() {
+            let player = self.players[(self.player_turn + i) % self.players.len()].clone();
+            if !player.borrow().is_bankrupt() {
+                players.push(player);
+            }
+        }
+        players
+    }
+    
+    pub fn get_auction(&self) -> Option<&Auction> {
+        self.auction.as_ref()
+    }
+    
+    /// The current bidder in the auction bids the given amount.
+    /// Returns false if there is no auction or the bid is refused.
+    pub fn bid(&mut self, amount: i32) -> bool {
+        let accepted = match self.auction {
+            Some(ref mut auction) => auction.bid(amount),
+            None => false,
+        };
+        self.check_auction_over();
+        accepted
+    }
+    
+    /// The current bidder in the auction drops out
+    pub fn pass_bid(&mut self) {
+        if let Some(ref mut auction) = self.auction {
+            auction.pass();
+        }
+        self.check_auction_over();
+    }
+    
+    /// Once the bidding is over, the winner pays their bid to the bank
+    /// and receives the property
+    fn check_auction_over(&mut self) {
+        let is_over = match self.auction {
+            Some(ref auction) => auction.is_over(),
+            None => false,
+        };
+        if !is_over {
+            return;
+        }
+        let auction = self.auction.take().unwrap();
+        let prop = match *auction.get_lot() {
+            Lot::Deed(ref prop) => prop.clone(),
+            Lot::House(ref prop) => {
+                self.sell_auctioned_house(prop.clone(), auction.get_winner());
+                return;
+            },
+        };
+        match auction.get_winner() {
+            Some((winner, bid)) => {
+                println!("{} won the auction for {} with a bid of ${}!",
+                         winner.borrow().get_name(),
+                         prop.borrow().get_name(),
+                         bid);
+                self.bank.sell_deed(&prop, &winner, bid, "auction");
+            },
+            None => {
+                println!("No one bid for {}; it stays with the bank.",
+                         prop.borrow().get_name());
+            },
+        }
+    }
+    
+    /// The winner of a house auction pays their bid and puts the house
+    /// on the property it was auctioned for if they own it, or else on
+    /// the first of their properties it may go on
+    fn sell_auctioned_house(&mut self, prop: Rc<RefCell<Property>>,
+                            winner: Option<(Rc<RefCell<Player>>, i32)>) {
+        let (winner, bid) = match winner {
+            Some(winner) => winner,
+            None => {
+                println!("No one bid for the house; it stays with the bank.");
+                return;
+            },
+        };
+        let spot = if self.can_place_house(&winner, &prop) {
+            Some(prop)
+        } else {
+            self.get_monopolies(&winner).into_iter()
+                .find(|prop| self.can_place_house(&winner, prop))
+        };
+        match spot {
+            Some(spot) => {
+                println!("{} won the house with a bid of ${} and put it on {}!",
+                         winner.borrow().get_name(), bid, spot.borrow().get_name());
+                let reason = format!("auctioned house on {}", spot.borrow().get_name());
+                self.bank.receive(&winner, bid, &reason);
+                self.bank.take_houses(1);
+                spot.borrow_mut().add_house();
+            },
+            None => println!("{} has nowhere to put the house; it stays with the bank.",
+                             winner.borrow().get_name()),
+        }
+    }
+    
+    /// The next property the bank took back from a bankrupt player,
+    /// to be put up for auction
+    pub fn take_returned_property(&mut self) -> Option<Rc<RefCell<Property>>> {
+        if self.returned_properties.is_empty() {
+            None
+        } else {
+            Some(self.returned_properties.remove(0))
+        }
+    }
+    
+    pub fn get_trade(&self) -> Option<&Trade> {
+        self.trade.as_ref()
+    }
+    
+    /// Put a trade to its receiver. Returns false (and nothing is
+    /// proposed) if the trade is not allowed.
+    pub fn propose_trade(&mut self, trade: Trade) -> bool {
+        if !self.validate_trade(&trade) {
+            return false;
+        }
+        println!("{} proposes a trade to {}.",
+                 trade.get_proposer().borrow().get_name(),
+                 trade.get_receiver().borrow().get_name());
+        trade.print();
+        self.trade = Some(trade);
+        true
+    }
+    
+    /// The receiver of the waiting trade answers it with a trade of
+    /// their own, which replaces it. Returns false if the counter is
+    /// not allowed, in which case the original trade still stands.
+    pub fn counter_trade(&mut self, counter: Trade) -> bool {
+        let is_counter = match self.trade {
+            Some(ref trade) =>
+                Rc::ptr_eq(&counter.get_proposer(), &trade.get_receiver()) &&
+                Rc::ptr_eq(&counter.get_receiver(), &trade.get_proposer()),
+            None => false,
+        };
+        if !is_counter {
+            println!("Only the player a trade was offered to can counter it!");
+            return false;
+        }
+        if !self.validate_trade(&counter) {
+            return false;
+        }
+        println!("{} counters.", counter.get_proposer().borrow().get_name());
+        self.trade = None;
+        self.propose_trade(counter)
+    }
+    
+    /// The receiver accepts the waiting trade, and the assets change
+    /// hands. Returns false if the trade is no longer allowed.
+    pub fn accept_trade(&mut self) -> bool {
+        let trade = match self.trade.take() {
+            Some(trade) => trade,
+            None => return false,
+        };
+        if !self.validate_trade(&trade) {
+            return false;
+        }
+        let proposer = trade.get_proposer();
+        let receiver = trade.get_receiver();
+        println!("{} accepts the trade.", receiver.borrow().get_name());
+        self.transfer_trade_side(trade.get_offered(), &proposer, &receiver);
+        self.transfer_trade_side(trade.get_requested(), &receiver, &proposer);
+        true
+    }
+    
+    pub fn reject_trade(&mut self) {
+        if let Some(trade) = self.trade.take() {
+            println!("{} rejects the trade.", trade.get_receiver().borrow().get_name());
+        }
+    }
+    
+    /// Move one side of a trade from giver to taker through the bank
+    fn transfer_trade_side(&mut self, side: &TradeSide, giver: &Rc<RefCell<Player>>,
+                           taker: &Rc<RefCell<Player>>) {
+        for prop in side.get_properties() {
+            self.bank.transfer_deed(prop, taker, "trade");
+        }
+        self.bank.transfer(giver, taker, side.get_cash(), "trade");
+        for card in side.get_jail_cards() {
+            giver.borrow_mut().remove_jail_card(*card);
+            taker.borrow_mut().add_jail_card(*card);
+        }
+    }
+    
+    /// True if both players can hand over their side of the trade.
+    /// Prints the reason if not.
+    pub fn validate_trade(&self, trade: &Trade) -> bool {
+        let proposer = trade.get_proposer();
+        let receiver = trade.get_receiver();
+        if Rc::ptr_eq(&proposer, &receiver) {
+            println!("A player cannot trade with themselves!");
+            return false;
+        }
+        if proposer.borrow().is_bankrupt() || receiver.borrow().is_bankrupt() {
+            println!("Bankrupt players cannot trade!");
+            return false;
+        }
+        if trade.get_offered().is_empty() && trade.get_requested().is_empty() {
+            println!("The trade is empty!");
+            return false;
+        }
+        self.validate_trade_side(trade.get_offered(), &proposer) &&
+            self.validate_trade_side(trade.get_requested(), &receiver)
+    }
+    
+    fn validate_trade_side(&self, side: &TradeSide,
+                           giver: &Rc<RefCell<Player>>) -> bool {
+        let giver = giver.borrow();
+        for prop in side.get_properties() {
+            if !giver.get_properties().contains(prop) {
+                println!("{} does not own {}!",
+                         giver.get_name(), prop.borrow().get_name());
+                return false;
+            }
+            let color_group = prop.borrow().get_color_group();
+            if self.group_has_buildings(&color_group) {
+                println!("{} cannot be traded while its color group has buildings!",
+                         prop.borrow().get_name());
+                return false;
+            }
+        }
+        if side.get_cash() < 0 {
+            println!("Cash in a trade cannot be negative!");
+            return false;
+        }
+        if side.get_cash() > giver.get_cash() {
+            println!("{} only has ${}!", giver.get_name(), giver.get_cash());
+            return false;
+        }
+        for card in &[JailCard::Chance, JailCard::CommunityChest] {
+            let offered = side.get_jail_cards().iter().filter(|c| *c == card).count();
+            let held = giver.get_jail_cards().iter().filter(|c| *c == card).count();
+            if offered > held {
+                println!("{} does not have that many {} cards!",
+                         giver.get_name(), card.get_name());
+                return false;
+            }
+        }
+        true
+    }
+    
+    /// True if any property in the color group has houses or a hotel
+    pub fn group_has_buildings(&self, color_group: &ColorGroup) -> bool {
+        self.get_group(color_group).iter().any(|prop| {
+            let prop = prop.borrow();
+            prop.has_houses() || prop.has_hotel()
+        })
+    }
+    
+    /// True if any property in the color group is mortgaged
+    pub fn group_has_mortgage(&self, color_group: &ColorGroup) -> bool {
+        self.get_group(color_group).iter().any(|prop| prop.borrow().is_mortgaged())
+    }
+    
+    /// All properties on the board in the given color group
+    pub fn get_group(&self, color_group: &ColorGroup) -> Vec<Rc<RefCell<Property>>> {
+        let mut props = Vec::new();
+        for space in &self.spaces {
+            if let SpaceEnum::Prop(ref prop) = *space.borrow().get_type() {
+                if prop.borrow().get_color_group() == *color_group {
+                    props.push(prop.clone());
+                }
+            }
+        }
+        props
+    }
+    
+    /// True if the player owns every property in the color group
+    pub fn has_monopoly(&self, player: &Rc<RefCell<Player>>,
+                        color_group: &ColorGroup) -> bool {
+        let group = self.get_group(color_group);
+        !group.is_empty() && group.iter().all(|prop| {
+            let prop = prop.borrow();
+            prop.is_owned() && prop.get_owner() == player
+        })
+    }
+    
+    /// All of the player's properties in a monopoly, in board order,
+    /// not counting railroads/utils; can't put houses and hotels on those
+    pub fn get_monopolies(&self, player: &Rc<RefCell<Player>>) -> Vec<Rc<RefCell<Property>>> {
+        let mut props = Vec::new();
+        for space in &self.spaces {
+            if let SpaceEnum::Prop(ref prop) = *space.borrow().get_type() {
+                let color_group = prop.borrow().get_color_group();
+                if color_group != ColorGroup::Railroad &&
+                   color_group != ColorGroup::Utility &&
+                   self.has_monopoly(player, &color_group) {
+                    props.push(prop.clone());
+                }
+            }
+        }
+        props
+    }
+    
+    /// The acting player mortgages one of their properties and is paid
+    /// its mortgage value. Returns false if the property cannot be
+    /// mortgaged.
+    pub fn mortgage_property(&mut self, prop: Rc<RefCell<Property>>) -> bool {
+        let player = self.get_acting_player();
+        let color_group = prop.borrow().get_color_group();
+        if !player.borrow().get_properties().contains(&prop) {
+            println!("You don't own {}!", prop.borrow().get_name());
+            return false;
+        }
+        if prop.borrow().is_mortgaged() {
+            println!("{} is already mortgaged!", prop.borrow().get_name());
+            return false;
+        }
+        if self.group_has_buildings(&color_group) {
+            println!("Sell the buildings on {}'s color group before mortgaging it!",
+                     prop.borrow().get_name());
+            return false;
+        }
+        let value = prop.borrow().get_mortgage_value();
+        println!("{} mortgaged {} for ${}.",
+                 player.borrow().get_name(),
+                 prop.borrow().get_name(),
+                 value);
+        prop.borrow_mut().set_mortgaged(true);
+        let reason = format!("mortgage on {}", prop.borrow().get_name());
+        self.bank.pay_out(&player, value, &reason);
+        true
+    }
+    
+    /// The acting player lifts the mortgage on one of their properties,
+    /// paying the mortgage value plus 10% interest. Returns false if
+    /// they cannot.
+    pub fn unmortgage_property(&mut self, prop: Rc<RefCell<Property>>) -> bool {
+        let player = self.get_acting_player();
+        if !player.borrow().get_properties().contains(&prop) {
+            println!("You don't own {}!", prop.borrow().get_name());
+            return false;
+        }
+        if !prop.borrow().is_mortgaged() {
+            println!("{} is not mortgaged!", prop.borrow().get_name());
+            return false;
+        }
+        let cost = prop.borrow().get_unmortgage_cost();
+        if player.borrow().get_cash() < cost {
+            println!("You need ${} to lift the mortgage on {}!",
+                     cost, prop.borrow().get_name());
+            return false;
+        }
+        println!("{} lifted the mortgage on {} for ${}.",
+                 player.borrow().get_name(),
+                 prop.borrow().get_name(),
+                 cost);
+        prop.borrow_mut().set_mortgaged(false);
+        let reason = format!("lifting the mortgage on {}", prop.borrow().get_name());
+        self.bank.receive(&player, cost, &reason);
+        true
+    }
+    
+    /// The acting player buys a house on one of their properties, or a
+    /// hotel once it has the most houses. Returns false if they cannot
+    /// build there.
+    pub fn buy_building(&mut self, prop: Rc<RefCell<Property>>) -> bool {
+        let player = self.get_acting_player();
+        if let Some(reason) = self.why_cannot_build(&player, &prop) {
+            println!("{}", reason);
+            self.print_building_choices(&player);
+            return false;
+        }
+        let cost = prop.borrow().get_building_cost();
+        let building = if prop.borrow().get_num_houses() >= MAX_NUM_HOUSES {
+            for _ in 0..MAX_NUM_HOUSES {
+                prop.borrow_mut().remove_house();
+            }
+            prop.borrow_mut().add_hotel();
+            self.bank.return_houses(MAX_NUM_HOUSES);
+            self.bank.take_hotel();
+            println!("Bought a hotel on {}!", prop.borrow().get_name());
+            "hotel"
+        } else {
+            let builders = self.get_house_builders();
+            if builders.len() > 1 && self.bank.get_houses() < builders.len() as i32 {
+                self.start_house_auction(prop, builders);
+                return false;
+            }
+            prop.borrow_mut().add_house();
+            self.bank.take_houses(1);
+            println!("Bought a house on {}!", prop.borrow().get_name());
+            "house"
+        };
+        let reason = format!("{} on {}", building, prop.borrow().get_name());
+        self.bank.receive(&player, cost, &reason);
+        true
+    }
+    
+    /// The acting player sells a house (or hotel) on one of their
+    /// properties back to the bank for half its cost. Returns false if
+    /// they cannot sell there.
+    pub fn sell_building(&mut self, prop: Rc<RefCell<Property>>) -> bool {
+        let player = self.get_acting_player();
+        if let Some(reason) = self.why_cannot_sell(&player, &prop) {
+            println!("{}", reason);
+            self.print_building_choices(&player);
+            return false;
+        }
+        if prop.borrow().has_hotel() && self.bank.get_houses() < MAX_NUM_HOUSES {
+            self.sell_down_hotels(&player, &prop);
+            return true;
+        }
+        let building = if prop.borrow().has_hotel() {
+            prop.borrow_mut().remove_hotel();
+            for _ in 0..MAX_NUM_HOUSES {
+                prop.borrow_mut().add_house();
+            }
+            self.bank.return_hotel();
+            self.bank.take_houses(MAX_NUM_HOUSES);
+            println!("Sold a hotel on {}!", prop.borrow().get_name());
+            "hotel"
+        } else {
+            prop.borrow_mut().remove_house();
+            self.bank.return_houses(1);
+            println!("Sold a house on {}!", prop.borrow().get_name());
+            "house"
+        };
+        let value = prop.borrow().get_building_cost() / 2;
+        let reason = format!("selling a {} on {}", building, prop.borrow().get_name());
+        self.bank.pay_out(&player, value, &reason);
+        true
+    }
+    
+    /// During a housing shortage a hotel cannot be traded back for
+    /// four houses. Instead it is broken down to as many houses as the
+    /// bank can supply and the rest is sold; with even building, the
+    /// whole color group is sold down evenly to what the bank's houses
+    /// allow.
+    fn sell_down_hotels(&mut self, player: &Rc<RefCell<Player>>,
+                        prop: &Rc<RefCell<Property>>) {
+        let color_group = prop.borrow().get_color_group();
+        let mut props = if self.rules.has_even_building() {
+            self.get_group(&color_group)
+        } else {
+            vec![prop.clone()]
+        };
+        props.sort_by_key(|prop| -prop.borrow().get_building_level());
+        let houses: i32 = self.bank.get_houses() + props.iter()
+            .map(|prop| prop.borrow().get_num_houses())
+            .sum::<i32>();
+        let num_props = props.len() as i32;
+        let total = cmp::min(houses, MAX_NUM_HOUSES * num_props);
+        println!("The bank has only {} houses left, so the hotel on {} is sold down \
+                  to what the bank can supply.", self.bank.get_houses(),
+                 prop.borrow().get_name());
+        // every building in the group goes back to the bank before any
+        // houses are handed out, so the bank never runs short midway
+        let mut new_levels = Vec::new();
+        for (i, prop) in props.iter().enumerate() {
+            let old_level = prop.borrow().get_building_level();
+            let share = total / num_props + if (i as i32) < total % num_props { 1 } else { 0 };
+            let new_level = cmp::min(share, old_level);
+            if new_level == old_level {
+                continue;
+            }
+            while prop.borrow().has_hotel() {
+                prop.borrow_mut().remove_hotel();
+                self.bank.return_hotel();
+            }
+            while prop.borrow().has_houses() {
+                prop.borrow_mut().remove_house();
+                self.bank.return_houses(1);
+            }
+            new_levels.push((prop.clone(), old_level, new_level));
+        }
+        let mut value = 0;
+        for (prop, old_level, new_level) in new_levels {
+            for _ in 0..new_level {
+                prop.borrow_mut().add_house();
+                self.bank.take_houses(1);
+            }
+            value += (old_level - new_level) * (prop.borrow().get_building_cost() / 2);
+            match new_level {
+                0 => println!("Sold every building on {}!", prop.borrow().get_name()),
+                1 => println!("{} is down to 1 house.", prop.borrow().get_name()),
+                n => println!("{} is down to {} houses.", prop.borrow().get_name(), n),
+            }
+        }
+        let reason = format!("selling buildings on {:?}", color_group);
+        self.bank.pay_out(player, value, &reason);
+    }
+    
+    /// Why the player cannot build on the property right now, if they
+    /// cannot
+    fn why_cannot_build(&self, player: &Rc<RefCell<Player>>,
+                        prop: &Rc<RefCell<Property>>) -> Option<String> {
+        if let Some(reason) = self.why_cannot_improve(player, prop) {
+            return Some(reason);
+        }
+        let name = prop.borrow().get_name();
+        let level = prop.borrow().get_building_level();
+        let building = if level >= MAX_NUM_HOUSES {
+            if self.bank.get_hotels() == 0 {
+                return Some("The bank has no hotels left!".to_string());
+            }
+            "hotel"
+        } else {
+            if self.bank.get_houses() == 0 {
+                return Some("The bank has no houses left!".to_string());
+            }
+            "house"
+        };
+        let cost = prop.borrow().get_building_cost();
+        if player.borrow().get_cash() < cost {
+            return Some(format!("You need ${} for a {} on {}!", cost, building, name));
+        }
+        None
+    }
+    
+    /// Why the next building on the property would break the building
+    /// rules for the player, leaving aside the bank's supply and what
+    /// it costs
+    fn why_cannot_improve(&self, player: &Rc<RefCell<Player>>,
+                          prop: &Rc<RefCell<Property>>) -> Option<String> {
+        let name = prop.borrow().get_name();
+        let color_group = prop.borrow().get_color_group();
+        if !player.borrow().get_properties().contains(prop) {
+            return Some(format!("You don't own {}!", name));
+        }
+        if color_group == ColorGroup::Railroad || color_group == ColorGroup::Utility ||
+           !self.has_monopoly(player, &color_group) {
+            return Some(format!("You need the whole of {}'s color group to build on it!",
+                                name));
+        }
+        if prop.borrow().get_num_hotels() >= MAX_NUM_HOTELS {
+            return Some(format!("{} cannot be further improved!", name));
+        }
+        if self.group_has_mortgage(&color_group) {
+            return Some(format!("You cannot build while a property in {}'s color \
+                                 group is mortgaged!", name));
+        }
+        let level = prop.borrow().get_building_level();
+        if self.rules.has_even_building() {
+            let lower = self.get_group(&color_group).into_iter()
+                .filter(|other| other.borrow().get_building_level() < level)
+                .map(|other| other.borrow().get_name())
+                .collect::<Vec<_>>();
+            if !lower.is_empty() {
+                return Some(format!("You must build evenly; build on {} before \
+                                     building on {} again!", lower.join(" and "), name));
+            }
+        }
+        None
+    }
+    
+    /// True if a house (rather than a hotel) may go on the property
+    /// next, leaving aside what it costs
+    fn can_place_house(&self, player: &Rc<RefCell<Player>>,
+                       prop: &Rc<RefCell<Property>>) -> bool {
+        prop.borrow().get_building_level() < MAX_NUM_HOUSES &&
+            self.why_cannot_improve(player, prop).is_none()
+    }
+    
+    /// The players who could afford a house and put it somewhere right
+    /// now, starting with the current player; if there are more of them
+    /// than houses left, the houses are auctioned
+    fn get_house_builders(&self) -> Vec<Rc<RefCell<Player>>> {
+        self.get_players_in_turn_order().into_iter()
+            .filter(|player| {
+                self.get_monopolies(player).iter().any(|prop| {
+                    self.can_place_house(player, prop) &&
+                        player.borrow().get_cash() >= prop.borrow().get_building_cost()
+                })
+            })
+            .collect()
+    }
+    
+    /// Why the player cannot sell a building on the property right now,
+    /// if they cannot
+    fn why_cannot_sell(&self, player: &Rc<RefCell<Player>>,
+                       prop: &Rc<RefCell<Property>>) -> Option<String> {
+        let name = prop.borrow().get_name();
+        if !player.borrow().get_properties().contains(prop) {
+            return Some(format!("You don't own {}!", name));
+        }
+        let level = prop.borrow().get_building_level();
+        if level == 0 {
+            return Some(format!("No houses to remove on {}!", name));
+        }
+        if self.rules.has_even_building() {
+            let higher = self.get_group(&prop.borrow().get_color_group()).into_iter()
+                .filter(|other| other.borrow().get_building_level() > level)
+                .map(|other| other.borrow().get_name())
+                .collect::<Vec<_>>();
+            if !higher.is_empty() {
+                return Some(format!("You must sell evenly; sell from {} before \
+                                     selling from {} again!", higher.join(" and "), name));
+            }
+        }
+        None
+    }
+    
+    /// The player's properties they can build on right now
+    pub fn get_buildable(&self, player: &Rc<RefCell<Player>>) -> Vec<Rc<RefCell<Property>>> {
+        self.get_monopolies(player).into_iter()
+            .filter(|prop| self.why_cannot_build(player, prop).is_none())
+            .collect()
+    }
+    
+    /// The player's properties they can sell a building from right now
+    pub fn get_sellable(&self, player: &Rc<RefCell<Player>>) -> Vec<Rc<RefCell<Property>>> {
+        self.get_monopolies(player).into_iter()
+            .filter(|prop| self.why_cannot_sell(player, prop).is_none())
+            .collect()
+    }
+    
+    /// Print where the player can build and sell right now
+    pub fn print_building_choices(&self, player: &Rc<RefCell<Player>>) {
+        let names = |props: Vec<Rc<RefCell<Property>>>| {
+            props.iter().map(|prop| prop.borrow().get_name()).collect::<Vec<_>>()
+        };
+        let buildable = names(self.get_buildable(player));
+        let sellable = names(self.get_sellable(player));
+        if buildable.is_empty() {
+            println!("You cannot build anywhere right now.");
+        } else {
+            println!("You can build on {}.", buildable.join(", "));
+        }
+        if !sellable.is_empty() {
+            println!("You can sell from {}.", sellable.join(", "));
+        }
+        println!("The bank has {} houses and {} hotels left.",
+                 self.bank.get_houses(), self.bank.get_hotels());
     }
     
     pub fn on_land_go(&mut self, salary: i32) {
+        let salary = if self.rules.has_double_go_salary() {
+            salary * 2
+        } else {
+            salary
+        };
         println!("You landed on GO! Collect ${}.", salary);
-        self.players[self.player_turn].borrow_mut().salary(salary);
+        let player = self.get_current_player();
+        self.bank.pay_out(&player, salary, "landing on GO");
     }
     
     pub fn get_num_remaining_players(&self) -> i32 {
@@ -203,53 +1152,145 @@ impl Board {
     }
     
     pub fn get_nearest_utility(&self) -> Rc<RefCell<Space>> {
-        let index = self.get_player_index();
-        if index < ELEC_UTIL {
-            self.get_space(ELEC_UTIL).clone()
-        } else if index < WATER_UTIL {
-            self.get_space(WATER_UTIL).clone()
-        } else {
-            self.get_space(ELEC_UTIL).clone()
-        }
+        self.get_next_in_group(&ColorGroup::Utility)
     }
     
     pub fn get_nearest_railroad(&self) -> Rc<RefCell<Space>> {
+        self.get_next_in_group(&ColorGroup::Railroad)
+    }
+    
+    /// The first space ahead of the current player holding a property
+    /// in the color group, going around the board
+    fn get_next_in_group(&self, color_group: &ColorGroup) -> Rc<RefCell<Space>> {
         let index = self.get_player_index();
-        if index < READING_RR {
-            self.get_space(READING_RR).clone()
-        } else if index < PA_RR {
-            self.get_space(PA_RR).clone()
-        } else if index < BO_RR {
-            self.get_space(BO_RR).clone()
-        } else if index < SL_RR {
-            self.get_space(SL_RR).clone()
-        } else {
-            self.get_space(READING_RR).clone()
+        for i in 1..self.spaces.len() + 1 {
+            let space = self.get_space(index + i);
+            let in_group = match *space.borrow().get_type() {
+                SpaceEnum::Prop(ref prop) => prop.borrow().get_color_group() == *color_group,
+                _ => false,
+            };
+            if in_group {
+                return space;
+            }
         }
+        panic!("There is no {:?} on this board!", color_group);
     }
     
-    pub fn on_land_chance(&mut self) -> LandAction {
-        if self.chance_cards.len() == 0 {
-            self.shuffle_chance();
+    pub fn get_go(&self) -> Rc<RefCell<Space>> {
+        self.spaces[0].clone() // every board starts at GO
+    }
+    
+    pub fn get_jail(&self) -> Rc<RefCell<Space>> {
+        self.spaces.iter()
+            .find(|space| matches!(*space.borrow().get_type(), SpaceEnum::Jail))
+            .expect("Every board has a jail!")
+            .clone()
+    }
+    
+    /// The space a Chance card sends players to (see CARD_TARGETS)
+    fn get_card_space(&self, card: &str) -> Rc<RefCell<Space>> {
+        let index = self.layout.get_card_target(card)
+            .expect("Every board has the spaces the cards need!");
+        self.get_space(index)
+    }
+    
+    /// Draw the next Chance card; stacked cards come first, and must
+    /// still be in the deck. The card is discarded unless the player
+    /// keeps it.
+    fn draw_chance(&mut self) -> Chance {
+        let card = match self.random.next_stacked_chance() {
+            Some(card) => match self.chance_cards.take(&card) {
+                Some(card) => card,
+                None => panic!("The stacked {:?} card is not in the Chance deck; \
+                                a player must be holding it!", card),
+            },
+            None => self.chance_cards.draw(&mut self.random).unwrap(),
+        };
+        if card != Chance::GetOutOfJailFree {
+            self.chance_cards.discard(card.clone());
+        }
+        card
+    }
+    
+    /// Draw the next Community Chest card; stacked cards come first, and
+    /// must still be in the deck. The card is discarded unless the
+    /// player keeps it.
+    fn draw_comm_chest(&mut self) -> CommunityChest {
+        let card = match self.random.next_stacked_comm_chest() {
+            Some(card) => match self.comm_chest_cards.take(&card) {
+                Some(card) => card,
+                None => panic!("The stacked {:?} card is not in the Community Chest deck; \
+                                a player must be holding it!", card),
+            },
+            None => self.comm_chest_cards.draw(&mut self.random).unwrap(),
+        };
+        if card != CommunityChest::GetOutOfJailFree {
+            self.comm_chest_cards.discard(card.clone());
         }
+        card
+    }
+    
+    pub fn on_land_chance(&mut self) -> LandAction {
         println!("Landed on Chance");
-        let card = self.chance_cards.pop().unwrap();
+        let card = self.draw_chance();
+        let player = self.get_current_player();
         
         match card {
             Chance::AdvanceToGo => {
-                let space = self.get_space(GO);
+                let space = self.get_go();
                 println!("Advance to GO!");
                 self.advance_to(space.clone())
             },
+            Chance::AdvanceToIllinoisAvenue => {
+                let space = self.get_card_space("illinois");
+                println!("Advance to {}! If you pass GO, collect ${}.",
+                         space.borrow().get_name(),
+                         self.rules.get_go_salary());
+                self.advance_forward_to(space.clone())
+            },
+            Chance::AdvanceToStCharlesPlace => {
+                let space = self.get_card_space("st_charles");
+                println!("Advance to {}! If you pass GO, collect ${}.",
+                         space.borrow().get_name(),
+                         self.rules.get_go_salary());
+                self.advance_forward_to(space.clone())
+            },
             Chance::AdvanceToNearestUtility => {
                 let space = self.get_nearest_utility();
-                println!("Advance to nearest utility!");
-                self.advance_to(space.clone())
+                println!("Advance to nearest utility! If owned, throw dice and \
+                          pay the owner ten times the amount thrown.");
+                match self.advance_forward_to(space.clone()) {
+                    LandAction::Rent(prop, _) => {
+                        let roll = self.random.roll_dice();
+                        println!("{} threw a {} ({} and {}).",
+                                 player.borrow().get_name(),
+                                 roll.get_total(),
+                                 roll.get_first(),
+                                 roll.get_second());
+                        LandAction::Rent(prop, RentModifier::TimesDice(10, roll))
+                    },
+                    action => action,
+                }
             },
             Chance::AdvanceToNearestRailroad => {
                 let space = self.get_nearest_railroad();
-                println!("Advance to nearest railroad!");
-                self.advance_to(space.clone())
+                println!("Advance to nearest railroad! If owned, pay the owner \
+                          twice the rent.");
+                match self.advance_forward_to(space.clone()) {
+                    LandAction::Rent(prop, _) =>
+                        LandAction::Rent(prop, RentModifier::Double),
+                    action => action,
+                }
+            },
+            Chance::BankPaysDividend => {
+                println!("Bank pays you dividend of $50.");
+                self.bank.pay_out(&player, 50, "Chance: bank dividend");
+                LandAction::Nothing
+            },
+            Chance::GetOutOfJailFree => {
+                println!("Get Out of Jail Free! This card may be kept until needed.");
+                player.borrow_mut().add_jail_card(JailCard::Chance);
+                LandAction::Nothing
             },
             Chance::GoBack3Spaces => {
                 let current_index = self.get_player_index() as i32;
@@ -258,53 +1299,151 @@ impl Board {
                     if new_index >= 0 { 
                         new_index as usize
                     } else {
-                        self.spaces.len() + new_index as usize
+                        (self.spaces.len() as i32 + new_index) as usize
                     }
                 };
                 let new_space = self.get_space(new_index);
                 println!("Go back 3 spaces!");
                 self.advance_to(new_space)
             },
+            Chance::GoToJail => LandAction::GoToJail,
+            Chance::GeneralRepairs => {
+                let num_houses = player.borrow().get_num_houses();
+                let num_hotels = player.borrow().get_num_hotels();
+                let cost = num_houses * 25 + num_hotels * 100;
+                println!("Make general repairs on all your property: $25 per \
+                          house and $100 per hotel. Pay ${}.", cost);
+                self.pay_fee(player.clone(), cost, "Chance: general repairs");
+                LandAction::Nothing
+            },
+            Chance::PayPoorTax => {
+                println!("Pay poor tax of $15.");
+                self.pay_fee(player.clone(), 15, "Chance: poor tax");
+                LandAction::Nothing
+            },
+            Chance::TakeARideOnTheReading => {
+                let space = self.get_card_space("reading");
+                println!("Take a ride on {}! If you pass GO, collect ${}.",
+                         space.borrow().get_name(),
+                         self.rules.get_go_salary());
+                self.advance_forward_to(space.clone())
+            },
             Chance::AdvanceToBoardwalk => {
-                let space = self.get_space(BDWK);
-                println!("Advance to Boardwalk!");
+                let space = self.get_card_space("boardwalk");
+                println!("Advance to {}!", space.borrow().get_name());
                 self.advance_to(space.clone())
             },
+            Chance::ElectedChairmanOfTheBoard => {
+                println!("You have been elected Chairman of the Board. \
+                          Pay each player $50.");
+                for other in self.players.clone() {
+                    if other != player && !other.borrow().is_bankrupt() {
+                        self.pay(player.clone(), Some(other.clone()), 50,
+                                 "Chance: chairman of the board");
+                    }
+                }
+                LandAction::Nothing
+            },
+            Chance::BuildingLoanMatures => {
+                println!("Your building loan matures. Collect $150.");
+                self.bank.pay_out(&player, 150, "Chance: building loan");
+                LandAction::Nothing
+            },
         }
-        
     }
     
-    pub fn on_land_comm_chest(&mut self) {
-        if self.comm_chest_cards.len() == 0 {
-            self.shuffle_comm_chest();
-        }
+    pub fn on_land_comm_chest(&mut self) -> LandAction {
         println!("Landed on Community Chest");
-        let card = self.comm_chest_cards.pop().unwrap();
+        let card = self.draw_comm_chest();
         let player = self.get_current_player();
         
         match card {
             CommunityChest::AdvanceToGo => {
-                let space = self.get_space(GO);
+                let space = self.get_go();
                 println!("Advance to GO!");
-                self.advance_to(space.clone());
+                self.advance_to(space.clone())
             },
             CommunityChest::BankErrorInYourFavor => {
                 println!("Bank error in your favor! Collect $200.");
-                player.borrow_mut().salary(200);
+                self.bank.pay_out(&player, 200, "Community Chest: bank error");
+                LandAction::Nothing
+            },
+            CommunityChest::DoctorsFees => {
+                println!("Doctor's fees! Pay $50.");
+                self.pay_fee(player.clone(), 50, "Community Chest: doctor's fees");
+                LandAction::Nothing
+            },
+            CommunityChest::SaleOfStock => {
+                println!("From sale of stock you get $50.");
+                self.bank.pay_out(&player, 50, "Community Chest: sale of stock");
+                LandAction::Nothing
+            },
+            CommunityChest::GetOutOfJailFree => {
+                println!("Get Out of Jail Free! This card may be kept until needed.");
+                player.borrow_mut().add_jail_card(JailCard::CommunityChest);
+                LandAction::Nothing
+            },
+            CommunityChest::GoToJail => LandAction::GoToJail,
+            CommunityChest::HolidayFundMatures => {
+                println!("Holiday fund matures. Receive $100.");
+                self.bank.pay_out(&player, 100, "Community Chest: holiday fund");
+                LandAction::Nothing
+            },
+            CommunityChest::IncomeTaxRefund => {
+                println!("Income tax refund. Collect $20.");
+                self.bank.pay_out(&player, 20, "Community Chest: income tax refund");
+                LandAction::Nothing
+            },
+            CommunityChest::ItsYourBirthday => {
+                println!("It's your birthday! Collect $10 from every player.");
+                for other in self.players.clone() {
+                    if other != player && !other.borrow().is_bankrupt() {
+                        self.pay(other.clone(), Some(player.clone()), 10,
+                                 "Community Chest: birthday");
+                    }
+                }
+                LandAction::Nothing
+            },
+            CommunityChest::LifeInsuranceMatures => {
+                println!("Life insurance matures. Collect $100.");
+                self.bank.pay_out(&player, 100, "Community Chest: life insurance");
+                LandAction::Nothing
             },
-            CommunityChest::GoToJail => {
-                println!("Go to jail!");
-                let gtj = player.borrow().get_space();
-                let jail = self.get_space(10).clone();
-                gtj.borrow_mut().remove_player(player.clone());
-                jail.borrow_mut().add_player(player.clone());
-                player.borrow_mut().jail(self.spaces[JAIL].clone());
+            CommunityChest::HospitalFees => {
+                println!("Pay hospital fees of $100!");
+                self.pay_fee(player.clone(), 100, "Community Chest: hospital fees");
+                LandAction::Nothing
             },
             CommunityChest::PaySchoolFees => {
                 println!("Pay school fees of $50!");
-                player.borrow_mut().tax(50);
+                self.pay_fee(player.clone(), 50, "Community Chest: school fees");
+                LandAction::Nothing
             },
-        };
+            CommunityChest::ConsultancyFee => {
+                println!("Receive $25 consultancy fee.");
+                self.bank.pay_out(&player, 25, "Community Chest: consultancy fee");
+                LandAction::Nothing
+            },
+            CommunityChest::StreetRepairs => {
+                let num_houses = player.borrow().get_num_houses();
+                let num_hotels = player.borrow().get_num_hotels();
+                let cost = num_houses * 40 + num_hotels * 115;
+                println!("You are assessed for street repairs: $40 per house \
+                          and $115 per hotel. Pay ${}.", cost);
+                self.pay_fee(player.clone(), cost, "Community Chest: street repairs");
+                LandAction::Nothing
+            },
+            CommunityChest::SecondPrizeInABeautyContest => {
+                println!("You have won second prize in a beauty contest! Collect $10.");
+                self.bank.pay_out(&player, 10, "Community Chest: beauty contest");
+                LandAction::Nothing
+            },
+            CommunityChest::YouInherit => {
+                println!("You inherit $100.");
+                self.bank.pay_out(&player, 100, "Community Chest: inheritance");
+                LandAction::Nothing
+            },
+        }
     }
     
     pub fn on_land_jail(&mut self) {
@@ -313,48 +1452,95 @@ impl Board {
     
     pub fn on_land_free_parking(&mut self) {
         println!("Landed on Free Parking");
-        // TODO: add free parking salary??
+        if self.rules.has_free_parking_jackpot() {
+            let player = self.get_current_player();
+            println!("{} collects the Free Parking pot of ${}!",
+                     player.borrow().get_name(), self.jackpot);
+            self.bank.pay_out(&player, self.jackpot, "Free Parking pot");
+            self.jackpot = self.get_jackpot_seed();
+        }
+    }
+    
+    /// True if the property's owner is in jail and, by the house rules,
+    /// collects no rent there
+    pub fn is_owner_in_jail(&self, property: &Rc<RefCell<Property>>) -> bool {
+        let property = property.borrow();
+        self.rules.has_no_rent_in_jail() && property.is_owned() &&
+            property.get_owner().borrow().is_in_jail()
+    }
+    
+    pub fn get_rent(&self, property: Rc<RefCell<Property>>,
+                    modifier: &RentModifier) -> i32 {
+        if property.borrow().is_mortgaged() || self.is_owner_in_jail(&property) {
+            return 0;
+        }
+        match *modifier {
+            RentModifier::Normal => self.get_base_rent(property, self.last_roll),
+            RentModifier::Double => self.get_base_rent(property, self.last_roll) * 2,
+            RentModifier::TimesDice(multiplier, roll) => multiplier * roll.get_total(),
+        }
     }
     
-    pub fn get_rent(&self, property: Rc<RefCell<Property>>) -> i32 {
+    /// Explains how the rent on a property was worked out, if it
+    /// depends on the dice or on a card
+    pub fn explain_rent(&self, property: Rc<RefCell<Property>>,
+                        modifier: &RentModifier) -> Option<String> {
+        let is_utility = property.borrow().get_color_group() == ColorGroup::Utility;
+        match *modifier {
+            RentModifier::Normal if is_utility => {
+                let roll = self.last_roll.map_or(0, |roll| roll.get_total());
+                Some(format!("{} times the dice roll of {}",
+                             self.get_utility_multiplier(property), roll))
+            },
+            RentModifier::Normal => None,
+            RentModifier::Double => Some("twice the usual rent".to_string()),
+            RentModifier::TimesDice(multiplier, roll) =>
+                Some(format!("{} times the dice throw of {}",
+                             multiplier, roll.get_total())),
+        }
+    }
+    
+    /// Utility rent is a multiple of the dice that depends on how many
+    /// utilities the owner has (4 times or 10 times on the US deeds)
+    fn get_utility_multiplier(&self, property: Rc<RefCell<Property>>) -> i32 {
+        let owner = {
+            let property = property.borrow();
+            property.get_owner().clone()
+        };
+        let num_utilities = owner.borrow().get_num_props(&ColorGroup::Utility);
+        let property = property.borrow();
+        property.get_deed().get_rent(num_utilities as usize - 1)
+    }
+    
+    /// The rent on a property as printed on its deed, before any
+    /// card modifies it. Utility rent is based on the given dice roll.
+    fn get_base_rent(&self, property: Rc<RefCell<Property>>,
+                     roll: Option<DiceRoll>) -> i32 {
         let color_group = property.borrow().get_color_group();
-        let base_rent = property.borrow().get_base_rent();
+        let deed = property.borrow().get_deed().clone();
         let owner = {
             let property = property.borrow();
             property.get_owner().clone()
         };    
         let num_props = owner.borrow().get_num_props(&color_group);
-        let has_monopoly = owner.borrow().has_monopoly(color_group.clone());
+        let has_monopoly = self.has_monopoly(&owner, &color_group);
         let num_houses = property.borrow().get_num_houses();
         let num_hotels = property.borrow().get_num_hotels();
         match color_group {
-            ColorGroup::Railroad => {
-                match num_props {
-                    1 => base_rent,
-                    2 => base_rent * 2,
-                    3 => base_rent * 4,
-                    4 => base_rent * 8,
-                    _ => unreachable!(),
-                }
-            },
+            ColorGroup::Railroad => deed.get_rent(num_props as usize - 1),
             ColorGroup::Utility => {
-                match num_props {
-                    1 => base_rent * 4,
-                    2 => base_rent * 10,
-                    _ => unreachable!(),
-                }
+                let dice = roll.map_or(0, |roll| roll.get_total());
+                self.get_utility_multiplier(property.clone()) * dice
             },
             _ => {
-                if has_monopoly {
-                    if num_hotels >= 1 {
-                        base_rent * num_hotels as i32 * 40
-                    } else if num_houses >= 1 {
-                        base_rent * num_houses as i32 * 5
-                    } else {
-                        base_rent * 3
-                    }
+                if num_hotels >= 1 {
+                    deed.get_rent(MAX_NUM_HOUSES as usize + 1)
+                } else if num_houses >= 1 {
+                    deed.get_rent(num_houses as usize)
+                } else if has_monopoly {
+                    deed.get_rent(0) * 2 // unimproved monopolies charge double
                 } else {
-                    base_rent
+                    deed.get_rent(0)
                 }
             },
         }
@@ -365,22 +1551,29 @@ impl Board {
                   GO! Do not collect ${}!", go_salary);   
         let player = self.get_current_player();
         let gtj = player.borrow().get_space();
-        let jail = self.get_space(10).clone();
+        let jail = self.get_jail();
         gtj.borrow_mut().remove_player(player.clone());
         jail.borrow_mut().add_player(player.clone());
-        player.borrow_mut().jail(self.spaces[JAIL].clone());
+        player.borrow_mut().jail(jail);
     }
     
+    /// The current player pays the income tax amount they chose
     pub fn on_land_income_tax(&mut self, tax: i32) {
         println!("Income tax! Pay ${}.", tax);
         let player = self.get_current_player();
-        player.borrow_mut().tax(tax);
+        self.pay_fee(player, tax, "income tax");
+    }
+    
+    /// The percentage of the current player's net worth due as income
+    /// tax, if they choose to pay that instead of the flat amount
+    pub fn get_income_tax_percent_amt(&self, percent: i32) -> i32 {
+        self.get_current_player().borrow().get_net_worth() * percent / 100
     }
     
     pub fn on_land_luxury_tax(&mut self, tax: i32) {
         println!("Luxury tax! Pay ${}.", tax);
         let player = self.get_current_player();
-        player.borrow_mut().tax(tax);
+        self.pay_fee(player, tax, "luxury tax");
     }
     
     pub fn get_space(&self, index: usize) -> Rc<RefCell<Space>> {
@@ -388,22 +1581,107 @@ impl Board {
         self.spaces[index].clone()
     }
     
-    pub fn get_next_space(&mut self) -> Rc<RefCell<Space>> {
+    /// Roll the dice for the current player's move, keeping track of
+    /// how many doubles they have rolled in a row
+    pub fn roll_dice(&mut self) -> DiceRoll {
         let player = self.get_current_player();
-        let dice_roll = get_dice_roll_12() as usize;
-        println!("{} rolled a {}.",
+        let roll = self.random.roll_dice();
+        println!("{} rolled a {} ({} and {}).",
                  player.borrow().get_name(),
-                 dice_roll);
+                 roll.get_total(),
+                 roll.get_first(),
+                 roll.get_second());
+        if roll.is_doubles() {
+            self.num_doubles += 1;
+        } else {
+            self.num_doubles = 0;
+        }
+        self.last_roll = Some(roll);
+        roll
+    }
+    
+    pub fn get_last_roll(&self) -> Option<DiceRoll> {
+        self.last_roll
+    }
+    
+    /// True if the current player rolled doubles and gets another roll
+    pub fn can_roll_again(&self) -> bool {
+        let player = self.get_current_player();
+        let player = player.borrow();
+        self.num_doubles > 0 && !player.is_in_jail() && !player.is_bankrupt()
+    }
+    
+    /// The current player pays the fine to get out of jail.
+    /// Returns false (and stays in jail) if they cannot afford it.
+    pub fn pay_jail_fine(&mut self, fine: i32) -> bool {
+        let player = self.get_current_player();
+        if player.borrow().get_cash() < fine {
+            println!("You don't have enough money! Choose another option.");
+            return false;
+        }
+        println!("{} paid ${}.", player.borrow().get_name(), fine);
+        self.pay_fee(player.clone(), fine, "jail fine");
+        player.borrow_mut().unjail();
+        true
+    }
+    
+    /// The current player tries to roll doubles to get out of jail.
+    /// Doubles free them; after the third failed attempt they must pay
+    /// the fine. Either way they then move by the roll (but do not roll
+    /// again). Returns None if they stay in jail.
+    pub fn roll_in_jail(&mut self, fine: i32) -> Option<LandAction> {
+        let player = self.get_current_player();
+        let roll = self.roll_dice();
+        self.num_doubles = 0;
+        
+        if roll.is_doubles() {
+            println!("{} rolled doubles and is now free!",
+                     player.borrow().get_name());
+            player.borrow_mut().unjail();
+        } else {
+            player.borrow_mut().add_jail_turn();
+            let jail_turns = player.borrow().get_jail_turns();
+            if jail_turns < MAX_JAIL_TURNS {
+                println!("{} did not roll doubles and remains in jail! \
+                          ({} of {} attempts)",
+                         player.borrow().get_name(),
+                         jail_turns,
+                         MAX_JAIL_TURNS);
+                return None;
+            }
+            println!("{} did not roll doubles in {} attempts and must pay ${}.",
+                     player.borrow().get_name(),
+                     MAX_JAIL_TURNS,
+                     fine);
+            self.pay_fee(player.clone(), fine, "jail fine");
+            player.borrow_mut().unjail();
+        }
+        
+        let space = self.get_next_space(roll.get_total() as usize);
+        Some(self.advance_to(space.clone()))
+    }
+    
+    pub fn get_next_space(&mut self, dice_roll: usize) -> Rc<RefCell<Space>> {
         let old_player_index = self.get_player_index();
         let new_raw_index = old_player_index + dice_roll;
-        if new_raw_index >= self.spaces.len() {
-            self.passed_go = true;
+        if new_raw_index > self.spaces.len() {
+            self.passed_go = true; // landing exactly on GO pays on its own
         }
         let new_player_index = self.clip_player_index(new_raw_index);
         let new_space = self.get_space(new_player_index);
         new_space.clone()
     }
     
+    /// Move the current player forward to the given space, collecting
+    /// the GO salary if they pass GO on the way
+    pub fn advance_forward_to(&mut self, new_space: Rc<RefCell<Space>>) -> LandAction {
+        let new_index = new_space.borrow().get_index();
+        if new_index < self.get_player_index() && new_index != 0 { // GO is index 0
+            self.passed_go = true;
+        }
+        self.advance_to(new_space)
+    }
+    
     pub fn advance_to(&mut self, new_space: Rc<RefCell<Space>>) -> LandAction {
         let player = self.get_current_player();
         let old_space = player.borrow().get_space();
@@ -418,7 +1696,14 @@ impl Board {
     }
     
     pub fn roll_and_land(&mut self) -> LandAction {
-        let space = self.get_next_space();
+        let roll = self.roll_dice();
+        if self.num_doubles >= MAX_NUM_DOUBLES {
+            println!("{} rolled doubles {} times in a row!",
+                     self.get_current_player().borrow().get_name(),
+                     MAX_NUM_DOUBLES);
+            return LandAction::GoToJail;
+        }
+        let space = self.get_next_space(roll.get_total() as usize);
         self.advance_to(space.clone())
     }
     
@@ -427,6 +1712,28 @@ impl Board {
         println!("{} has ${} and the following assets:",
                 player.get_name(), player.get_cash());
         player.print_assets();
+        self.print_jackpot();
+    }
+    
+    /// Show the current player every transaction they have been a
+    /// party to
+    pub fn print_statement(&self) {
+        let player = self.get_current_player();
+        self.bank.get_ledger().print_statement(&player);
+        println!("{} has ${}.", player.borrow().get_name(), player.borrow().get_cash());
+    }
+    
+    /// The number of the turn being played, counting every player's
+    /// turn; 0 while the game is being set up
+    pub fn get_turn(&self) -> i32 {
+        self.bank.get_ledger().get_turn()
+    }
+    
+    /// Show the Free Parking pot, if that house rule is on
+    pub fn print_jackpot(&self) {
+        if self.rules.has_free_parking_jackpot() {
+            println!("The Free Parking pot holds ${}.", self.jackpot);
+        }
     }
     
     pub fn end_turn(&mut self) {
@@ -446,205 +1753,34 @@ impl Board {
         space.get_index()
     }
     
-    pub fn reset_spaces(&mut self) {  
-        let go = Space::new(SpaceEnum::Go, 522, 520, GO);
-        let med_ave = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "Mediterranean Avenue".to_string(),
-                                  60,
-                                  2,
-                                  ColorGroup::DarkPurple)))), 472, 520, MED_AVE);
-        let comm_chest_bot = Space::new(SpaceEnum::CommunityChest, 426, 520, COMM_CHEST_BOT);
-        let balt_ave = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "Baltic Avenue".to_string(),
-                                  60,
-                                  4,
-                                  ColorGroup::DarkPurple)))), 376, 520, BALT_AVE);  
-        let income_tax = Space::new(SpaceEnum::IncomeTax, 328, 520, INCOME_TAX);
-        let reading_rr = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "Reading Railroad".to_string(),
-                                  150,
-                                  25,
-                                  ColorGroup::Railroad)))), 280, 520, READING_RR);    
-        let orient_ave = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "Oriental Avenue".to_string(),
-                                  100,
-                                  6,
-                                  ColorGroup::LightBlue)))), 231, 520, ORIENT_AVE);
-        let chance_bot = Space::new(SpaceEnum::Chance, 183, 520, CHANCE_BOT);
-        let verm_ave = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "Vermont Avenue".to_string(),
-                                  100,
-                                  6,
-                                  ColorGroup::LightBlue)))), 134, 520, VERM_AVE);          
-        let conn_ave = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "Connecticut Avenue".to_string(),
-                                  120,
-                                  8,
-                                  ColorGroup::LightBlue)))), 88, 520, CONN_AVE);  
-        let jail = Space::new(SpaceEnum::Jail, 4, 520, JAIL);                                  
-        let st_char_pl = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "St. Charles Place".to_string(),
-                                  140,
-                                  10,
-                                  ColorGroup::LightPurple)))), 4, 472, ST_CHAR_PL);
-        let elec_util = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "Electric Company".to_string(),
-                                  150,
-                                  8,
-                                  ColorGroup::Utility)))), 4, 424, ELEC_UTIL);                                  
-        let states_ave = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "States Avenue".to_string(),
-                                  140,
-                                  10,
-                                  ColorGroup::LightPurple)))), 4, 376, STATES_AVE);   
-        let va_ave = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "Virginia Avenue".to_string(),
-                                  160,
-                                  12,
-                                  ColorGroup::LightPurple)))), 4, 327, VA_AVE);       
-        let pa_rr = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "Pennsylvania Railroad".to_string(),
-                                  150,
-                                  25,
-                                  ColorGroup::Railroad)))), 4, 280, PA_RR);   
-        let st_james_pl = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "St. James Place".to_string(),
-                                  180,
-                                  14,
-                                  ColorGroup::Orange)))), 4, 230, ST_JAMES_PL);      
-        let comm_chest_left = Space::new(SpaceEnum::CommunityChest, 4, 182, COMM_CHEST_LEFT);
-        let tn_ave = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "Tennessee Avenue".to_string(),
-                                  180,
-                                  14,
-                                  ColorGroup::Orange)))), 4, 133, TN_AVE);       
-        let ny_ave = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "New York Avenue".to_string(),
-                                  200,
-                                  16,
-                                  ColorGroup::Orange)))), 4, 85, NY_AVE);     
-        let free_parking = Space::new(SpaceEnum::FreeParking, 4, 4, FREE_PARKING);
-        let ky_ave = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "Kentucky Avenue".to_string(),
-                                  220,
-                                  18,
-                                  ColorGroup::Red)))), 88, 4, KY_AVE);     
-        let chance_top = Space::new(SpaceEnum::Chance, 135, 4, CHANCE_TOP);
-        let in_ave = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "Indiana Avenue".to_string(),
-                                  220,
-                                  18,
-                                  ColorGroup::Red)))), 184, 4, IN_AVE);       
-        let il_ave = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "Illinois Avenue".to_string(),
-                                  240,
-                                  20,
-                                  ColorGroup::Red)))), 232, 4, IL_AVE);       
-        let bo_rr = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "B&O Railroad".to_string(),
-                                  150,
-                                  25,
-                                  ColorGroup::Railroad)))), 280, 4, BO_RR);                                   
-        let atl_ave = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "Atlantic Avenue".to_string(),
-                                  260,
-                                  22,
-                                  ColorGroup::Yellow)))), 328, 4, ATL_AVE);      
-        let ventnor_ave = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "Ventnor Avenue".to_string(),
-                                  260,
-                                  22,
-                                  ColorGroup::Yellow)))), 377, 4, VENTNOR_AVE);      
-        let water_util = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "Water Works".to_string(),
-                                  150,
-                                  8,
-                                  ColorGroup::Utility)))), 425, 4, WATER_UTIL);  
-        let mar_gard = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "Marvin Gardens".to_string(),
-                                  280,
-                                  22,
-                                  ColorGroup::Yellow)))), 474, 4, MAR_GARD);      
-        let go_to_jail = Space::new(SpaceEnum::GoToJail, 522, 4, GO_TO_JAIL);
-        let pac_ave = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "Pacific Avenue".to_string(),
-                                  300,
-                                  26,
-                                  ColorGroup::Green)))), 522, 85, PAC_AVE);     
-        let nc_ave = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "North Carolina Avenue".to_string(),
-                                  300,
-                                  26,
-                                  ColorGroup::Green)))), 522, 133, NC_AVE);       
-        let comm_chest_right = Space::new(SpaceEnum::CommunityChest, 522, 181, COMM_CHEST_RIGHT);
-        let pa_ave = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "Pennsylvania Avenue".to_string(),
-                                  320,
-                                  28,
-                                  ColorGroup::Green)))), 522, 230, PA_AVE);    
-        let sl_rr = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "Short Line".to_string(),
-                                  150,
-                                  25,
-                                  ColorGroup::Railroad)))), 522, 279, SL_RR);      
-        let chance_right = Space::new(SpaceEnum::Chance, 522, 327, CHANCE_RIGHT);
-        let park_pl = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "Park Place".to_string(),
-                                  350,
-                                  35,
-                                  ColorGroup::DarkBlue)))), 522, 375, PARK_PL); 
-        let luxury_tax = Space::new(SpaceEnum::LuxuryTax, 522, 424, LUXURY_TAX);
-        let bdwk = Space::new(SpaceEnum::Prop ( Rc::new(RefCell::new(Property::new(
-                                  "Boardwalk".to_string(),
-                                  400,
-                                  50,
-                                  ColorGroup::DarkBlue)))), 522, 472, BDWK);      
-
-        self.spaces = Vec::new();
-        self.spaces.push(Rc::new(RefCell::new(go)));
-        self.spaces.push(Rc::new(RefCell::new(med_ave)));
-        self.spaces.push(Rc::new(RefCell::new(comm_chest_bot)));
-        self.spaces.push(Rc::new(RefCell::new(balt_ave)));
-        self.spaces.push(Rc::new(RefCell::new(income_tax)));
-        self.spaces.push(Rc::new(RefCell::new(reading_rr)));
-        self.spaces.push(Rc::new(RefCell::new(orient_ave)));
-        self.spaces.push(Rc::new(RefCell::new(chance_bot)));
-        self.spaces.push(Rc::new(RefCell::new(verm_ave)));
-        self.spaces.push(Rc::new(RefCell::new(conn_ave)));
-        self.spaces.push(Rc::new(RefCell::new(jail)));
-        self.spaces.push(Rc::new(RefCell::new(st_char_pl)));
-        self.spaces.push(Rc::new(RefCell::new(elec_util)));
-        self.spaces.push(Rc::new(RefCell::new(states_ave)));
-        self.spaces.push(Rc::new(RefCell::new(va_ave)));
-        self.spaces.push(Rc::new(RefCell::new(pa_rr)));
-        self.spaces.push(Rc::new(RefCell::new(st_james_pl)));
-        self.spaces.push(Rc::new(RefCell::new(comm_chest_left)));
-        self.spaces.push(Rc::new(RefCell::new(tn_ave)));
-        self.spaces.push(Rc::new(RefCell::new(ny_ave)));
-        self.spaces.push(Rc::new(RefCell::new(free_parking)));
-        self.spaces.push(Rc::new(RefCell::new(ky_ave)));
-        self.spaces.push(Rc::new(RefCell::new(chance_top)));
-        self.spaces.push(Rc::new(RefCell::new(in_ave)));
-        self.spaces.push(Rc::new(RefCell::new(il_ave)));
-        self.spaces.push(Rc::new(RefCell::new(bo_rr)));
-        self.spaces.push(Rc::new(RefCell::new(atl_ave)));
-        self.spaces.push(Rc::new(RefCell::new(ventnor_ave)));
-        self.spaces.push(Rc::new(RefCell::new(water_util)));
-        self.spaces.push(Rc::new(RefCell::new(mar_gard)));
-        self.spaces.push(Rc::new(RefCell::new(go_to_jail)));
-        self.spaces.push(Rc::new(RefCell::new(pac_ave)));
-        self.spaces.push(Rc::new(RefCell::new(nc_ave)));
-        self.spaces.push(Rc::new(RefCell::new(comm_chest_right)));
-        self.spaces.push(Rc::new(RefCell::new(pa_ave)));
-        self.spaces.push(Rc::new(RefCell::new(sl_rr)));
-        self.spaces.push(Rc::new(RefCell::new(chance_right)));
-        self.spaces.push(Rc::new(RefCell::new(park_pl)));
-        self.spaces.push(Rc::new(RefCell::new(luxury_tax)));
-        self.spaces.push(Rc::new(RefCell::new(bdwk)));  
+    /// Lay out fresh spaces from the board layout, with no owners,
+    /// buildings or players
+    pub fn reset_spaces(&mut self) {
+        self.spaces = self.layout.get_spaces().iter().enumerate()
+            .map(|(index, space)| Rc::new(RefCell::new(space.build(index))))
+            .collect();
+        let deeds = self.spaces.iter()
+            .filter_map(|space| match *space.borrow().get_type() {
+                SpaceEnum::Prop(ref prop) => Some(prop.clone()),
+                _ => None,
+            })
+            .collect();
+        self.bank.reset_deeds(deeds);
     }
     
+    /// A new player joins and the bank pays them the starting cash
     pub fn add_player(&mut self, player: Rc<RefCell<Player>>) {
         self.players.push(player.clone());
+        let cash = self.rules.get_starting_cash();
+        self.bank.pay_out(&player, cash, "starting cash");
+    }
+    
+    pub fn get_spaces(&self) -> &Vec<Rc<RefCell<Space>>> {
+        &self.spaces
+    }
+    
+    pub fn get_players(&self) -> &Vec<Rc<RefCell<Player>>> {
+        &self.players
     }
     
     /// Returns the player whose turn is currently up
@@ -658,26 +1794,27 @@ impl Board {
     }
 }
 
-impl Render for Board {
-    fn render(&self, gl: &mut GlGraphics, args: &RenderArgs) {
-        use graphics::*;
-    
-        gl.draw(args.viewport(), |c, gl| {
-            let transform = c.transform.trans(0.0, 0.0);
-            image(&(self.image), transform, gl);
-        });
-        
-        for space in &(self.spaces) {
-            space.borrow().render(gl, args);
-        }
-    }
-}
-
 
 /*
  *  UTILITY FUNCTIONS
  */
 
+/// The index of the creditor still owed the most, if anyone is owed
+/// anything; the earliest debt wins a tie
+fn get_largest_debt(debts: &[(Option<Rc<RefCell<Player>>>, i32)]) -> Option<usize> {
+    let mut largest: Option<usize> = None;
+    for (i, debt) in debts.iter().enumerate() {
+        let is_larger = match largest {
+            Some(j) => debt.1 > debts[j].1,
+            None => true,
+        };
+        if debt.1 > 0 && is_larger {
+            largest = Some(i);
+        }
+    }
+    largest
+}
+
 pub fn get_token_color() -> usize {
     loop {
         let color = get_string();
@@ -719,6 +1856,7 @@ pub fn get_turn_action() -> TurnCommand {
             "roll" => return TurnCommand::Roll,
             "quit"  => return TurnCommand::Quit,
             "assets" => return TurnCommand::Assets,
+            "statement" => return TurnCommand::Statement,
             _ => print!("Please enter a valid command: "),
         }
     }
@@ -740,28 +1878,625 @@ pub fn get_int() -> i32 {
 }
 
 /// Get the desired number of players
-pub fn get_num_players() -> i32 {
+pub fn get_num_players(max_num_players: i32) -> i32 {
     loop {
         let n = get_int();
-        if n < 2 || n > MAX_NUM_PLAYERS {
+        if n < 2 || n > max_num_players {
             print!("Number of players must be >= 2 and <= {}: ",
-                     MAX_NUM_PLAYERS);
+                     max_num_players);
         } else {
             return n;
         }
     }
 }
 
-pub fn get_dice_roll_6() -> i32 {
-    let mut rng = rand::thread_rng();
-    let result: i32 = rng.gen_range(1, 7);
-    result
-}
 
-/// Simulate a dice roll: return an integer between 2 and 12, inclusive
-pub fn get_dice_roll_12() -> i32 {
-    let mut rng = rand::thread_rng();
-    let first: i32 = rng.gen_range(1, 7); // [1, 7)
-    let second: i32 = rng.gen_range(1, 7);
-    first + second
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::ledger::*;
+
+    /// A standard board with the named players on GO, the first to
+    /// move, whose dice and cards come only from what the test loads
+    /// and stacks
+    fn new_board_with(names: &[&str]) -> (Board, Vec<Rc<RefCell<Player>>>) {
+        let mut board = Board::with_random(Random::from_seed(1));
+        board.reset_spaces();
+        let go = board.get_space(0);
+        let mut players = Vec::new();
+        for name in names {
+            let player = Rc::new(RefCell::new(Player::new(name.to_string(), go.clone(), RED)));
+            go.borrow_mut().add_player(player.clone());
+            board.add_player(player.clone());
+            players.push(player);
+        }
+        board.start_turn();
+        (board, players)
+    }
+
+    /// A standard board with players A and B, A to move
+    fn new_board() -> (Board, Rc<RefCell<Player>>, Rc<RefCell<Player>>) {
+        let (board, mut players) = new_board_with(&["A", "B"]);
+        let b = players.pop().unwrap();
+        let a = players.pop().unwrap();
+        (board, a, b)
+    }
+
+    fn get_property(board: &Board, index: usize) -> Rc<RefCell<Property>> {
+        match *board.get_space(index).borrow().get_type() {
+            SpaceEnum::Prop(ref prop) => prop.clone(),
+            _ => panic!("space {} is not a property", index),
+        }
+    }
+
+    /// A buys the property, then it is B's turn
+    fn buy_and_pass(board: &mut Board, index: usize) -> Rc<RefCell<Property>> {
+        let prop = get_property(board, index);
+        board.on_purchase(prop.clone());
+        board.end_turn();
+        board.start_turn();
+        prop
+    }
+
+    #[test]
+    fn third_doubles_in_a_row_goes_to_jail() {
+        let (mut board, a, _) = new_board();
+        board.get_random_mut().load_dice(&[1, 1, 2, 2, 3, 3]);
+        board.roll_and_land();
+        assert!(board.can_roll_again());
+        board.roll_and_land();
+        assert!(board.can_roll_again());
+        let action = board.roll_and_land();
+        assert!(matches!(action, LandAction::GoToJail));
+        assert_eq!(a.borrow().get_space().borrow().get_index(), 6);
+
+        board.on_land_go_to_jail(200);
+        assert!(a.borrow().is_in_jail());
+        assert_eq!(a.borrow().get_space().borrow().get_index(), 10);
+        assert!(!board.can_roll_again());
+    }
+
+    #[test]
+    fn third_failed_jail_roll_pays_the_fine_and_moves() {
+        let (mut board, a, _) = new_board();
+        board.on_land_go_to_jail(200);
+        board.get_random_mut().load_dice(&[1, 2, 1, 2, 1, 3]);
+        assert!(board.roll_in_jail(50).is_none());
+        assert!(board.roll_in_jail(50).is_none());
+        assert_eq!(a.borrow().get_cash(), 1500);
+
+        let action = board.roll_in_jail(50);
+        assert!(matches!(action, Some(LandAction::MightPurchase(_))));
+        assert!(!a.borrow().is_in_jail());
+        assert_eq!(a.borrow().get_cash(), 1450);
+        assert_eq!(a.borrow().get_space().borrow().get_index(), 14); // jail + 4
+        assert!(!board.can_roll_again());
+    }
+
+    #[test]
+    fn doubles_in_jail_free_the_player_without_the_fine() {
+        let (mut board, a, _) = new_board();
+        board.on_land_go_to_jail(200);
+        board.get_random_mut().load_dice(&[4, 4]);
+        assert!(board.roll_in_jail(50).is_some());
+        assert!(!a.borrow().is_in_jail());
+        assert_eq!(a.borrow().get_cash(), 1500);
+        assert_eq!(a.borrow().get_space().borrow().get_index(), 18);
+        assert!(!board.can_roll_again());
+    }
+
+    #[test]
+    fn used_jail_card_goes_to_the_bottom_of_its_deck() {
+        let (mut board, a, _) = new_board();
+        board.get_random_mut().stack_chance(vec![Chance::GetOutOfJailFree]);
+        board.on_land_chance();
+        assert!(a.borrow().has_jail_card());
+        assert!(!board.get_chance_cards().get_draw_pile().contains(&Chance::GetOutOfJailFree));
+        assert!(!board.get_chance_cards().get_discard_pile().contains(&Chance::GetOutOfJailFree));
+
+        board.on_land_go_to_jail(200);
+        assert!(board.use_jail_card());
+        assert!(!a.borrow().is_in_jail());
+        assert!(!a.borrow().has_jail_card());
+        assert_eq!(board.get_chance_cards().get_draw_pile().last(),
+                   Some(&Chance::GetOutOfJailFree));
+    }
+
+    #[test]
+    fn nearest_railroad_card_charges_double_rent() {
+        let (mut board, a, b) = new_board();
+        let reading = buy_and_pass(&mut board, 5);
+        board.get_random_mut().stack_chance(vec![Chance::AdvanceToNearestRailroad]);
+        let action = board.on_land_chance();
+        let modifier = match action {
+            LandAction::Rent(ref prop, ref modifier) => {
+                assert!(Rc::ptr_eq(prop, &reading));
+                modifier.clone()
+            },
+            _ => panic!("expected to pay rent on Reading Railroad"),
+        };
+        assert_eq!(modifier, RentModifier::Double);
+        assert_eq!(board.get_rent(reading.clone(), &RentModifier::Normal), 25);
+        assert_eq!(board.get_rent(reading.clone(), &modifier), 50);
+
+        let owner_cash = a.borrow().get_cash();
+        board.on_rent_collected(a.clone(), reading, &modifier);
+        assert_eq!(a.borrow().get_cash(), owner_cash + 50);
+        assert_eq!(b.borrow().get_cash(), 1500 - 50);
+    }
+
+    #[test]
+    fn nearest_utility_card_charges_ten_times_a_fresh_roll() {
+        let (mut board, a, b) = new_board();
+        let electric = buy_and_pass(&mut board, 12);
+        board.get_random_mut().load_dice(&[6, 5, 2, 3]);
+        board.roll_dice(); // the roll that brought B to Chance
+        board.get_random_mut().stack_chance(vec![Chance::AdvanceToNearestUtility]);
+        let action = board.on_land_chance();
+        let modifier = match action {
+            LandAction::Rent(ref prop, ref modifier) => {
+                assert!(Rc::ptr_eq(prop, &electric));
+                modifier.clone()
+            },
+            _ => panic!("expected to pay rent on Electric Company"),
+        };
+        assert_eq!(modifier, RentModifier::TimesDice(10, DiceRoll::new(2, 3)));
+        assert_eq!(board.get_rent(electric.clone(), &modifier), 50);
+
+        let owner_cash = a.borrow().get_cash();
+        board.on_rent_collected(a.clone(), electric, &modifier);
+        assert_eq!(a.borrow().get_cash(), owner_cash + 50);
+        assert_eq!(b.borrow().get_cash(), 1500 - 50);
+    }
+
+    #[test]
+    fn utility_rent_is_a_multiple_of_the_last_roll() {
+        let (mut board, _, _) = new_board();
+        let electric = get_property(&board, 12);
+        let water = get_property(&board, 28);
+        board.on_purchase(electric.clone());
+        board.get_random_mut().load_dice(&[3, 4]);
+        board.roll_dice();
+        assert_eq!(board.get_rent(electric.clone(), &RentModifier::Normal), 28);
+        assert_eq!(board.explain_rent(electric.clone(), &RentModifier::Normal),
+                   Some("4 times the dice roll of 7".to_string()));
+
+        board.on_purchase(water);
+        assert_eq!(board.get_rent(electric.clone(), &RentModifier::Normal), 70);
+        assert_eq!(board.explain_rent(electric, &RentModifier::Normal),
+                   Some("10 times the dice roll of 7".to_string()));
+    }
+
+    #[test]
+    fn auction_goes_around_the_table_skipping_the_high_bidder() {
+        let (mut board, players) = new_board_with(&["A", "B", "C"]);
+        let (a, b, c) = (&players[0], &players[1], &players[2]);
+        let prop = get_property(&board, 1);
+        board.start_auction(prop.clone());
+        let is_bidding = |board: &Board, player: &Rc<RefCell<Player>>| {
+            Rc::ptr_eq(&board.get_auction().unwrap().get_current_bidder().unwrap(), player)
+        };
+        assert!(is_bidding(&board, a));
+        assert!(board.bid(10));
+        assert!(is_bidding(&board, b));
+        board.pass_bid();
+        assert!(is_bidding(&board, c));
+        assert!(board.bid(20));
+        assert!(is_bidding(&board, a)); // B has dropped out
+        board.pass_bid();
+
+        assert!(board.get_auction().is_none());
+        assert!(Rc::ptr_eq(prop.borrow().get_owner(), c));
+        assert!(c.borrow().get_properties().contains(&prop));
+        assert_eq!(c.borrow().get_cash(), 1480);
+        assert_eq!(a.borrow().get_cash(), 1500);
+        assert_eq!(b.borrow().get_cash(), 1500);
+    }
+
+    #[test]
+    fn auction_refuses_low_and_unaffordable_bids() {
+        let (mut board, a, _) = new_board();
+        board.start_auction(get_property(&board, 1));
+        assert!(!board.bid(MIN_BID_INCREMENT - 1));
+        assert!(!board.bid(a.borrow().get_cash() + 10));
+        let auction = board.get_auction().unwrap();
+        assert_eq!(auction.get_high_bid(), 0);
+        assert!(Rc::ptr_eq(&auction.get_current_bidder().unwrap(), &a));
+    }
+
+    #[test]
+    fn auction_without_bids_leaves_the_property_unowned() {
+        let (mut board, a, b) = new_board();
+        let prop = get_property(&board, 1);
+        board.start_auction(prop.clone());
+        board.pass_bid();
+        board.pass_bid();
+        assert!(board.get_auction().is_none());
+        assert!(!prop.borrow().is_owned());
+        assert_eq!(a.borrow().get_cash(), 1500);
+        assert_eq!(b.borrow().get_cash(), 1500);
+    }
+
+    #[test]
+    fn accepted_trade_swaps_properties_cash_and_jail_cards() {
+        let (mut board, a, b) = new_board();
+        let mediterranean = get_property(&board, 1);
+        board.on_purchase(mediterranean.clone());
+        b.borrow_mut().add_jail_card(JailCard::Chance);
+        let mut trade = Trade::new(a.clone(), b.clone());
+        trade.get_offered_mut().add_property(mediterranean.clone());
+        trade.get_offered_mut().set_cash(100);
+        trade.get_requested_mut().add_jail_card(JailCard::Chance);
+        assert!(board.propose_trade(trade));
+        assert!(board.accept_trade());
+
+        assert!(board.get_trade().is_none());
+        assert!(Rc::ptr_eq(mediterranean.borrow().get_owner(), &b));
+        assert!(b.borrow().get_properties().contains(&mediterranean));
+        assert!(a.borrow().get_properties().is_empty());
+        assert_eq!(a.borrow().get_cash(), 1500 - 60 - 100);
+        assert_eq!(b.borrow().get_cash(), 1600);
+        assert_eq!(a.borrow().get_jail_cards(), &vec![JailCard::Chance]);
+        assert!(!b.borrow().has_jail_card());
+    }
+
+    #[test]
+    fn trade_is_refused_unless_both_sides_can_hand_it_over() {
+        let (mut board, a, b) = new_board();
+        let mediterranean = get_property(&board, 1);
+        let baltic = get_property(&board, 3);
+        board.on_purchase(mediterranean.clone());
+        board.on_purchase(baltic.clone());
+
+        let mut empty = Trade::new(a.clone(), b.clone());
+        assert!(!board.validate_trade(&empty));
+        empty.get_offered_mut().set_cash(10);
+        assert!(board.validate_trade(&empty));
+        assert!(!board.validate_trade(&Trade::new(a.clone(), a.clone())));
+
+        let mut not_owned = Trade::new(b.clone(), a.clone());
+        not_owned.get_offered_mut().add_property(baltic.clone());
+        assert!(!board.validate_trade(&not_owned));
+
+        let mut too_much_cash = Trade::new(a.clone(), b.clone());
+        too_much_cash.get_requested_mut().set_cash(1501);
+        assert!(!board.validate_trade(&too_much_cash));
+
+        let mut no_card = Trade::new(a.clone(), b.clone());
+        no_card.get_offered_mut().add_jail_card(JailCard::CommunityChest);
+        assert!(!board.validate_trade(&no_card));
+
+        let mut built_on = Trade::new(a.clone(), b.clone());
+        built_on.get_offered_mut().add_property(mediterranean.clone());
+        assert!(board.validate_trade(&built_on));
+        baltic.borrow_mut().add_house();
+        assert!(!board.validate_trade(&built_on));
+        assert!(!board.propose_trade(built_on));
+        assert!(board.get_trade().is_none());
+    }
+
+    #[test]
+    fn shortfall_becomes_a_debt_to_each_creditor() {
+        let (mut board, a, b) = new_board();
+        a.borrow_mut().tax(1440);
+        board.pay(a.clone(), Some(b.clone()), 50, "rent");
+        board.pay(a.clone(), Some(b.clone()), 30, "rent");
+        board.pay(a.clone(), None, 100, "tax");
+        assert_eq!(a.borrow().get_cash(), 0);
+        assert_eq!(b.borrow().get_cash(), 1560);
+        assert_eq!(a.borrow().get_debt(), 120);
+        let owed: Vec<(String, i32)> = a.borrow().get_debts().iter()
+            .map(|debt| (debt.get_creditor_name(), debt.get_amount()))
+            .collect();
+        assert_eq!(owed, vec![("B".to_string(), 20), ("the bank".to_string(), 100)]);
+        assert!(Rc::ptr_eq(&board.get_debtor().unwrap(), &a));
+
+        a.borrow_mut().salary(50);
+        board.settle_debt(a.clone());
+        assert_eq!(b.borrow().get_cash(), 1580);
+        assert_eq!(a.borrow().get_cash(), 0);
+        assert_eq!(a.borrow().get_debt(), 70);
+        assert_eq!(a.borrow().get_debts()[0].get_creditor_name(), "the bank");
+    }
+
+    #[test]
+    fn debtor_who_can_raise_funds_is_not_bankrupt() {
+        let (mut board, a, b) = new_board();
+        board.on_purchase(get_property(&board, 39)); // mortgage value $200
+        let cash = a.borrow().get_cash();
+        a.borrow_mut().tax(cash);
+        board.pay(a.clone(), Some(b.clone()), 150, "rent");
+        board.handle_bankruptcy();
+        assert!(!a.borrow().is_bankrupt());
+        assert_eq!(a.borrow().get_debt(), 150);
+        assert!(Rc::ptr_eq(&board.get_acting_player(), &a));
+    }
+
+    #[test]
+    fn debtor_who_cannot_raise_funds_goes_to_the_creditor_owed_the_most() {
+        let (mut board, players) = new_board_with(&["A", "B", "C"]);
+        let (a, b, c) = (&players[0], &players[1], &players[2]);
+        let mediterranean = get_property(&board, 1);
+        board.on_purchase(mediterranean.clone());
+        let cash = a.borrow().get_cash();
+        a.borrow_mut().tax(cash - 30);
+        board.get_random_mut().stack_chance(vec![Chance::ElectedChairmanOfTheBoard]);
+        board.on_land_chance();
+        assert_eq!(a.borrow().get_debt(), 70); // $20 to B and $50 to C
+
+        board.handle_bankruptcy();
+        assert!(a.borrow().is_bankrupt());
+        assert!(!a.borrow().is_in_debt());
+        assert!(Rc::ptr_eq(mediterranean.borrow().get_owner(), c));
+        assert!(c.borrow().get_properties().contains(&mediterranean));
+        assert_eq!(b.borrow().get_cash(), 1530);
+        assert!(board.get_debtor().is_none());
+    }
+
+    #[test]
+    fn bankrupt_debtors_deeds_are_split_among_creditors() {
+        let (mut board, players) = new_board_with(&["A", "B", "C"]);
+        let (a, b, c) = (&players[0], &players[1], &players[2]);
+        let mediterranean = get_property(&board, 1); // mortgage value $30
+        let boardwalk = get_property(&board, 39); // mortgage value $200
+        for prop in &[&mediterranean, &boardwalk] {
+            board.on_purchase((*prop).clone());
+            assert!(board.mortgage_property((*prop).clone()));
+        }
+        let cash = a.borrow().get_cash();
+        a.borrow_mut().tax(cash - 30);
+        board.get_random_mut().stack_chance(vec![Chance::ElectedChairmanOfTheBoard]);
+        board.on_land_chance();
+        board.handle_bankruptcy();
+
+        // C is owed $50 and takes Mediterranean, leaving $20 owed to
+        // each; B's debt is older, so B takes Boardwalk. Each pays 10%
+        // interest on the mortgage.
+        assert!(a.borrow().is_bankrupt());
+        assert!(a.borrow().get_properties().is_empty());
+        assert!(Rc::ptr_eq(mediterranean.borrow().get_owner(), c));
+        assert!(Rc::ptr_eq(boardwalk.borrow().get_owner(), b));
+        assert!(mediterranean.borrow().is_mortgaged());
+        assert_eq!(c.borrow().get_cash(), 1500 - 3);
+        assert_eq!(b.borrow().get_cash(), 1500 + 30 - 20);
+    }
+
+    #[test]
+    fn bankrupt_debtors_buildings_are_sold_before_settling() {
+        let (mut board, a, b) = new_board();
+        let mediterranean = get_property(&board, 1);
+        let baltic = get_property(&board, 3);
+        board.on_purchase(mediterranean.clone());
+        board.on_purchase(baltic.clone());
+        baltic.borrow_mut().add_house();
+        let cash = a.borrow().get_cash();
+        a.borrow_mut().tax(cash);
+        board.pay(a.clone(), Some(b.clone()), 1000, "rent");
+        board.handle_bankruptcy();
+
+        assert!(a.borrow().is_bankrupt());
+        assert!(!baltic.borrow().has_houses());
+        assert_eq!(b.borrow().get_cash(), 1500 + 25); // half the house's cost
+        assert!(Rc::ptr_eq(baltic.borrow().get_owner(), &b));
+    }
+
+    #[test]
+    fn bankrupt_debtors_deeds_owed_to_the_bank_go_up_for_auction() {
+        let (mut board, a, _) = new_board();
+        let mediterranean = get_property(&board, 1);
+        board.on_purchase(mediterranean.clone());
+        assert!(board.mortgage_property(mediterranean.clone()));
+        a.borrow_mut().add_jail_card(JailCard::Chance);
+        let cash = a.borrow().get_cash();
+        a.borrow_mut().tax(cash);
+        board.pay(a.clone(), None, 500, "tax");
+        board.handle_bankruptcy();
+
+        assert!(a.borrow().is_bankrupt());
+        assert!(!mediterranean.borrow().is_owned());
+        assert!(!mediterranean.borrow().is_mortgaged());
+        assert!(!a.borrow().has_jail_card());
+        assert_eq!(board.take_returned_property(), Some(mediterranean));
+        assert_eq!(board.take_returned_property(), None);
+    }
+
+    #[test]
+    fn buildings_must_be_bought_and_sold_evenly_across_a_group() {
+        let (mut board, a, _) = new_board();
+        let mediterranean = get_property(&board, 1);
+        let baltic = get_property(&board, 3);
+        board.on_purchase(mediterranean.clone());
+        board.on_purchase(baltic.clone());
+        let cash = a.borrow().get_cash();
+
+        assert!(board.buy_building(baltic.clone()));
+        assert!(!board.buy_building(baltic.clone()));
+        assert!(board.buy_building(mediterranean.clone()));
+        assert!(board.buy_building(baltic.clone()));
+        assert_eq!(mediterranean.borrow().get_building_level(), 1);
+        assert_eq!(baltic.borrow().get_building_level(), 2);
+        assert_eq!(a.borrow().get_cash(), cash - 150);
+
+        assert!(!board.sell_building(mediterranean.clone()));
+        assert!(board.sell_building(baltic.clone()));
+        assert!(board.sell_building(mediterranean.clone()));
+        assert!(!board.sell_building(mediterranean.clone()));
+        assert_eq!(baltic.borrow().get_building_level(), 1);
+        assert_eq!(a.borrow().get_cash(), cash - 150 + 50);
+    }
+
+    #[test]
+    fn no_house_is_built_once_the_bank_runs_out() {
+        let (mut board, a, _) = new_board();
+        let mediterranean = get_property(&board, 1);
+        let baltic = get_property(&board, 3);
+        board.on_purchase(mediterranean.clone());
+        board.on_purchase(baltic.clone());
+        let cash = a.borrow().get_cash();
+
+        let houses = board.get_bank().get_houses();
+        board.bank.take_houses(houses - 1);
+        assert!(board.buy_building(baltic.clone()));
+        assert_eq!(board.get_bank().get_houses(), 0);
+        assert!(!board.buy_building(mediterranean.clone()));
+        assert!(!mediterranean.borrow().has_houses());
+        assert_eq!(a.borrow().get_cash(), cash - 50);
+
+        assert!(board.sell_building(baltic.clone()));
+        assert_eq!(board.get_bank().get_houses(), 1);
+        assert!(board.buy_building(mediterranean.clone()));
+    }
+
+    #[test]
+    fn bank_keeps_unsold_deeds_and_counts_the_money_it_moves() {
+        let (mut board, a, b) = new_board();
+        let num_deeds = board.get_bank().get_deeds().len();
+        let mediterranean = get_property(&board, 1);
+        board.on_purchase(mediterranean.clone());
+        assert_eq!(board.get_bank().get_deeds().len(), num_deeds - 1);
+        assert!(mediterranean.borrow().is_owned());
+        assert!(board.mortgage_property(mediterranean.clone()));
+        assert_eq!(board.get_bank().get_paid_in(), 60);
+        assert_eq!(board.get_bank().get_paid_out(), 3000 + 30);
+
+        board.pay(a.clone(), Some(b.clone()), 100, "rent");
+        assert_eq!(board.get_bank().get_balance(), 30 - 3000);
+        assert_eq!(a.borrow().get_cash() + b.borrow().get_cash() +
+                   board.get_bank().get_balance(), 0);
+
+        board.bank.take_back_deed(&mediterranean, "bankruptcy");
+        assert_eq!(board.get_bank().get_deeds().len(), num_deeds);
+        assert!(!mediterranean.borrow().is_owned());
+        assert!(!mediterranean.borrow().is_mortgaged());
+        assert!(a.borrow().get_properties().is_empty());
+    }
+
+    #[test]
+    fn ledger_statements_add_up_to_each_players_cash() {
+        let (mut board, a, b) = new_board();
+        let mediterranean = get_property(&board, 1);
+        board.on_purchase(mediterranean.clone());
+        board.pay(a.clone(), Some(b.clone()), 10, "rent");
+        board.mortgage_property(mediterranean.clone());
+
+        let ledger = board.get_bank().get_ledger();
+        for player in &[a.clone(), b.clone()] {
+            let statement = ledger.get_statement(player);
+            assert_eq!(statement[0].get_turn(), 0);
+            assert_eq!(statement[0].get_reason(), "starting cash");
+            let total: i32 = statement.iter().map(|t| t.get_cash_change(player)).sum();
+            assert_eq!(total, player.borrow().get_cash());
+        }
+        let statement = ledger.get_statement(&a);
+        assert_eq!(statement.len(), 5);
+        assert!(statement.iter().any(|t| matches!(*t.get_item(), Item::Deed(_))));
+        assert_eq!(ledger.get_statement(&b).len(), 2);
+    }
+
+    #[test]
+    fn hotels_are_sold_down_evenly_during_a_housing_shortage() {
+        let (mut board, a, _) = new_board();
+        let mediterranean = get_property(&board, 1);
+        let baltic = get_property(&board, 3);
+        board.on_purchase(mediterranean.clone());
+        board.on_purchase(baltic.clone());
+        for prop in &[mediterranean.clone(), baltic.clone()] {
+            prop.borrow_mut().add_hotel();
+            board.bank.take_hotel();
+        }
+        let houses = board.get_bank().get_houses();
+        board.bank.take_houses(houses - 2);
+        let cash = a.borrow().get_cash();
+
+        assert!(board.sell_building(mediterranean.clone()));
+        assert_eq!(mediterranean.borrow().get_building_level(), 1);
+        assert_eq!(baltic.borrow().get_building_level(), 1);
+        assert_eq!(board.get_bank().get_houses(), 0);
+        assert_eq!(board.get_bank().get_hotels(), NUM_BANK_HOTELS);
+        assert_eq!(a.borrow().get_cash(), cash + 8 * 25);
+    }
+
+    #[test]
+    fn selling_a_hotel_in_a_shortage_returns_the_groups_buildings_first() {
+        let (mut board, a, _) = new_board();
+        let st_james = get_property(&board, 16);
+        let tennessee = get_property(&board, 18);
+        let new_york = get_property(&board, 19);
+        for prop in &[st_james.clone(), tennessee.clone(), new_york.clone()] {
+            board.on_purchase(prop.clone());
+        }
+        for prop in &[st_james.clone(), tennessee.clone()] {
+            prop.borrow_mut().add_hotel();
+            board.bank.take_hotel();
+        }
+        for _ in 0..MAX_NUM_HOUSES {
+            new_york.borrow_mut().add_house();
+        }
+        let houses = board.get_bank().get_houses();
+        board.bank.take_houses(houses - 2);
+        let cash = a.borrow().get_cash();
+
+        assert!(board.sell_building(st_james.clone()));
+        for prop in &[st_james, tennessee, new_york] {
+            assert_eq!(prop.borrow().get_building_level(), 2);
+        }
+        assert_eq!(board.get_bank().get_houses(), 0);
+        assert_eq!(board.get_bank().get_hotels(), NUM_BANK_HOTELS);
+        assert_eq!(a.borrow().get_cash(), cash + 8 * 50);
+    }
+
+    #[test]
+    fn invalid_counter_trade_leaves_the_original_standing() {
+        let (mut board, a, b) = new_board();
+        let mediterranean = get_property(&board, 1);
+        board.on_purchase(mediterranean.clone());
+        let mut trade = Trade::new(a.clone(), b.clone());
+        trade.get_offered_mut().add_property(mediterranean.clone());
+        trade.get_requested_mut().set_cash(100);
+        assert!(board.propose_trade(trade));
+
+        let mut counter = Trade::new(b.clone(), a.clone());
+        counter.get_offered_mut().set_cash(5000);
+        counter.get_requested_mut().add_property(mediterranean.clone());
+        assert!(!board.counter_trade(counter));
+        assert!(Rc::ptr_eq(&board.get_trade().unwrap().get_proposer(), &a));
+
+        let mut counter = Trade::new(b.clone(), a.clone());
+        counter.get_offered_mut().set_cash(50);
+        counter.get_requested_mut().add_property(mediterranean.clone());
+        assert!(board.counter_trade(counter));
+        assert!(Rc::ptr_eq(&board.get_trade().unwrap().get_proposer(), &b));
+        assert_eq!(board.get_trade().unwrap().get_offered().get_cash(), 50);
+    }
+
+    #[test]
+    fn debtor_who_gives_up_stays_in_if_their_buildings_cover_the_debt() {
+        let (mut board, a, b) = new_board();
+        let mediterranean = get_property(&board, 1);
+        let baltic = get_property(&board, 3);
+        board.on_purchase(mediterranean.clone());
+        board.on_purchase(baltic.clone());
+        baltic.borrow_mut().add_house();
+        let cash = a.borrow().get_cash();
+        a.borrow_mut().tax(cash);
+        board.pay(a.clone(), Some(b.clone()), 20, "rent");
+        assert!(board.give_up());
+
+        assert!(!a.borrow().is_bankrupt());
+        assert!(!a.borrow().is_in_debt());
+        assert_eq!(a.borrow().get_cash(), 5);
+        assert_eq!(b.borrow().get_cash(), 1520);
+        assert!(!baltic.borrow().has_houses());
+        assert!(Rc::ptr_eq(baltic.borrow().get_owner(), &a));
+    }
+
+    #[test]
+    #[should_panic(expected = "a player must be holding it")]
+    fn stacked_card_a_player_holds_cannot_be_drawn() {
+        let (mut board, a, _) = new_board();
+        board.chance_cards.take(&Chance::GetOutOfJailFree);
+        a.borrow_mut().add_jail_card(JailCard::Chance);
+        board.get_random_mut().stack_chance(vec![Chance::GetOutOfJailFree]);
+        board.on_land_chance();
+    }
 }
@@ -1,26 +1,189 @@
 //
 //! Cards are used whenever a player lands on a Chance or a
-//! Community Chest space. They are meant to be randomly ordered and
-//! drawn from a Vector in the Board struct.
+//! Community Chest space. Each kind of card is kept in a Deck in the
+//! Board struct, which is shuffled by the Board's Random.
 //!
 //! Cards may grant players a sum of money, require them to pay a fee,
 //! advance them to a specified space, or give them a Get Out of Jail Free
-//! Card (planned for a future release)
+//! Card. A player keeps a Get Out of Jail Free Card until it is used,
+//! and it then goes back to the bottom of the deck it came from.
 //!
 
+use super::random::*;
+
+/// A deck of cards with a draw pile and a discard pile. Drawn cards
+/// go to the discard pile once they have been read, except for cards
+/// held back by a player, which are in neither pile until they are
+/// returned. The discard pile is only shuffled back into the draw
+/// pile once the draw pile runs out.
+#[derive(Debug, Clone)]
+pub struct Deck<T> {
+    draw_pile: Vec<T>, // the top card is first
+    discard_pile: Vec<T>,
+}
+
+impl<T: Clone + PartialEq> Deck<T> {
+    /// A deck with the given cards in the draw pile, top card first
+    pub fn new(cards: Vec<T>) -> Deck<T> {
+        Deck::from_piles(cards, Vec::new())
+    }
+
+    /// A deck restored from its piles (e.g. from a saved game)
+    pub fn from_piles(draw_pile: Vec<T>, discard_pile: Vec<T>) -> Deck<T> {
+        Deck {
+            draw_pile: draw_pile,
+            discard_pile: discard_pile,
+        }
+    }
+
+    /// Shuffle the discard pile back into the draw pile, then put the
+    /// whole draw pile in a random order
+    pub fn shuffle(&mut self, random: &mut Random) {
+        self.draw_pile.append(&mut self.discard_pile);
+        random.shuffle(&mut self.draw_pile);
+    }
+
+    /// Take the top card of the draw pile, shuffling the discard pile
+    /// first if the draw pile has run out. Returns None only if every
+    /// card is held back by a player.
+    pub fn draw(&mut self, random: &mut Random) -> Option<T> {
+        if self.draw_pile.is_empty() {
+            self.shuffle(random);
+        }
+        if self.draw_pile.is_empty() {
+            None
+        } else {
+            Some(self.draw_pile.remove(0))
+        }
+    }
+
+    /// Take a specific card out of whichever pile it is in
+    pub fn take(&mut self, card: &T) -> Option<T> {
+        if let Some(i) = self.draw_pile.iter().position(|c| c == card) {
+            return Some(self.draw_pile.remove(i));
+        }
+        if let Some(i) = self.discard_pile.iter().position(|c| c == card) {
+            return Some(self.discard_pile.remove(i));
+        }
+        None
+    }
+
+    pub fn discard(&mut self, card: T) {
+        self.discard_pile.push(card);
+    }
+
+    /// Put a card that was held back at the bottom of the draw pile
+    pub fn put_on_bottom(&mut self, card: T) {
+        self.draw_pile.push(card);
+    }
+
+    /// The draw pile, top card first
+    pub fn get_draw_pile(&self) -> &Vec<T> {
+        &self.draw_pile
+    }
+
+    /// The discard pile, most recently discarded card last
+    pub fn get_discard_pile(&self) -> &Vec<T> {
+        &self.discard_pile
+    }
+}
+
 /// Represents a Community Chest card
+#[derive(Debug, Clone, PartialEq)]
 pub enum CommunityChest {
     AdvanceToGo,
     BankErrorInYourFavor,
+    DoctorsFees,
+    SaleOfStock,
+    GetOutOfJailFree,
     GoToJail,
+    HolidayFundMatures,
+    IncomeTaxRefund,
+    ItsYourBirthday,
+    LifeInsuranceMatures,
+    HospitalFees,
     PaySchoolFees,
+    ConsultancyFee,
+    StreetRepairs,
+    SecondPrizeInABeautyContest,
+    YouInherit,
+}
+
+/// The sixteen cards of the Community Chest deck
+pub fn comm_chest_deck() -> Vec<CommunityChest> {
+    vec![CommunityChest::AdvanceToGo,
+         CommunityChest::BankErrorInYourFavor,
+         CommunityChest::DoctorsFees,
+         CommunityChest::SaleOfStock,
+         CommunityChest::GetOutOfJailFree,
+         CommunityChest::GoToJail,
+         CommunityChest::HolidayFundMatures,
+         CommunityChest::IncomeTaxRefund,
+         CommunityChest::ItsYourBirthday,
+         CommunityChest::LifeInsuranceMatures,
+         CommunityChest::HospitalFees,
+         CommunityChest::PaySchoolFees,
+         CommunityChest::ConsultancyFee,
+         CommunityChest::StreetRepairs,
+         CommunityChest::SecondPrizeInABeautyContest,
+         CommunityChest::YouInherit]
 }
 
 /// Represents a Chance card
+#[derive(Debug, Clone, PartialEq)]
 pub enum Chance {
     AdvanceToGo,
+    AdvanceToIllinoisAvenue,
+    AdvanceToStCharlesPlace,
     AdvanceToNearestUtility,
     AdvanceToNearestRailroad,
+    BankPaysDividend,
+    GetOutOfJailFree,
     GoBack3Spaces,
+    GoToJail,
+    GeneralRepairs,
+    PayPoorTax,
+    TakeARideOnTheReading,
     AdvanceToBoardwalk,
+    ElectedChairmanOfTheBoard,
+    BuildingLoanMatures,
+}
+
+/// The sixteen cards of the Chance deck (there are two
+/// Advance to Nearest Railroad cards)
+pub fn chance_deck() -> Vec<Chance> {
+    vec![Chance::AdvanceToGo,
+         Chance::AdvanceToIllinoisAvenue,
+         Chance::AdvanceToStCharlesPlace,
+         Chance::AdvanceToNearestUtility,
+         Chance::AdvanceToNearestRailroad,
+         Chance::AdvanceToNearestRailroad,
+         Chance::BankPaysDividend,
+         Chance::GetOutOfJailFree,
+         Chance::GoBack3Spaces,
+         Chance::GoToJail,
+         Chance::GeneralRepairs,
+         Chance::PayPoorTax,
+         Chance::TakeARideOnTheReading,
+         Chance::AdvanceToBoardwalk,
+         Chance::ElectedChairmanOfTheBoard,
+         Chance::BuildingLoanMatures]
+}
+
+/// A Get Out of Jail Free card held by a player, remembering
+/// which deck it has to be returned to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JailCard {
+    Chance,
+    CommunityChest,
+}
+
+impl JailCard {
+    pub fn get_name(&self) -> String {
+        match *self {
+            JailCard::Chance => "Get Out of Jail Free (Chance)".to_string(),
+            JailCard::CommunityChest =>
+                "Get Out of Jail Free (Community Chest)".to_string(),
+        }
+    }
 }
\ No newline at end of file
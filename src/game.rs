@@ -2,21 +2,29 @@
 //! The Game struct stores the entirety of the game state, including the
 //! game board, whether the game is running or not, 
 //! the current state of a player's turn, and the player's command.
-//! It also stores the window in which game objects are drawn.
+//! It does not own a window; a frontend (such as the Piston one in
+//! the gui crate) feeds it key presses through handle_key_input()
+//! and advances it with update(). A headless driver can do the same,
+//! using setup_players() instead of the console prompts.
 //!
 //! The game proceeds as follows:
 //! 1. Player prompted for action.
-//! 2. Player enters key corresponding to action in the console.
+//! 2. Player enters key corresponding to action in the window.
 //! 3. Game executes the player's action.
 //! 4. If the game requires a response to the action (such as landing on
 //!    unowned property), the player will be prompted to respond by
-//!    typing a key in the window.
-//! 5. After the player's turn is finished, the game will check if they
-//!    are bankrupt. If so, the player's assets will be transferred to
-//!    the creditor (or the bank if there is no creditor). The bankrupt
+//!    typing a key in the window. A property the player declines or
+//!    cannot afford is auctioned off to all players.
+//! 5. A player who rolled doubles goes back to step 1 and rolls again;
+//!    rolling doubles three times in a row sends them to jail instead.
+//! 6. After the player's turn is finished, a player who could not pay
+//!    what they owe must raise funds by selling houses and mortgaging.
+//!    If even that would not cover the debt, they are bankrupt and
+//!    their assets are shared out among the players (or the bank) they
+//!    owe. The bankrupt
 //!    player will no longer be able to trade properties, collect rents,
 //!    or otherwise participate in the game.
-//! 6. The last remaining player wins the game.
+//! 7. The last remaining player wins the game.
 //!
 //! During the course of the game, the Game will notify its Board that 
 //! the next player is ready to begin his turn via board.start_turn().
@@ -25,39 +33,23 @@
 //! renting/etc); see LandAction for more details.
 //!
 
-extern crate opengl_graphics;
-extern crate piston;
-extern crate glutin_window;
-
 use std::collections::BTreeMap;
-use glutin_window::GlutinWindow;
-use std::process;
-use piston::window::WindowSettings;
-use piston::event_loop::*;
-use piston::input::*;
 use std::rc::Rc;
 use std::cell::RefCell;
-use opengl_graphics::{GlGraphics, OpenGL};
 
 use super::board::*;
 use super::player::*;
 use super::property::*;
 use super::space::*;
+use super::random::*;
+use super::auction::*;
+use super::trade::*;
+use super::cards::*;
+use super::rules::*;
+use super::layout::*;
+use super::audit::*;
 
 
-pub const WINDOW_WIDTH: i32 = 600;
-pub const WINDOW_HEIGHT: i32 = 600;
-
-pub const GO_SALARY: i32 = 200;
-pub const INCOME_TAX_AMT: i32 = 200;
-pub const LUXURY_TAX_AMT: i32 = 75;
-
-/// Objects that can be drawn to the screen with
-/// the Piston/OpenGL framework
-pub trait Render {
-    fn render(&self, gl: &mut GlGraphics, args: &RenderArgs);
-}
-
 /// Represents the different stages in a player's turn
 #[derive(Debug, PartialEq, Clone)]
 pub enum TurnState {
@@ -75,6 +67,36 @@ pub enum TurnState {
     ValidatePropIndex,
     BuyHouseHotel,
     SellHouseHotel,
+    StartAuctionBid,
+    EnterBid,
+    ValidateBid,
+    StartTradeMenu,
+    TradeMenu,
+    EnterTradeValue(TradeInput),
+    ValidateTradeValue(TradeInput),
+    StartTradeResponse,
+    TradeResponse,
+    StartRaiseFunds,
+    RaiseFunds,
+    ConfirmGiveUp,
+    ConfirmIncomeTax,
+}
+
+/// How a player chooses to pay income tax
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum IncomeTaxChoice {
+    Flat, // the rules' flat income tax amount
+    Percent, // the rules' percentage of net worth
+}
+
+/// The value being typed in while putting a trade together
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TradeInput {
+    Partner,
+    OfferProperty,
+    RequestProperty,
+    OfferCash,
+    RequestCash,
 }
 
 /// Represents a player's choice of action during their turn
@@ -83,11 +105,13 @@ pub enum TurnCommand {
     Roll,
     Quit,
     Assets,
+    Statement,
+    Audit,
     PayJailFine,
     UseJailCard,
     HouseHotel,
+    Mortgage,
     Trade,
-    // TODO: add more types of actions (trades, buy/sell houses)
 }
 
 /// Master game state: is the game running/set up/over?
@@ -97,46 +121,127 @@ pub enum GameState {
     GameStateSetup,
     GameRun,
     GameOver,
+    GameQuit,
 }
 
 pub struct Game {
-    main_window: GlutinWindow,
-    gl: GlGraphics,
     board: Board,
     game_state: GameState,
     turn_state: TurnState,
     turn_command: Option<TurnCommand>,
     key_queue: Vec<u8>,
+    prop_command: Option<TurnCommand>, // what the entered property index is for
+    trade_draft: Option<Trade>, // the trade being put together, if any
+    house_auction: bool, // the auction is for a house the player asked
+                         // to build, so it returns to the command prompt
+    auto_income_tax: bool, // choose the cheaper income tax for players
+    debug: bool, // audit the board after every turn
+    rules: RuleSet, // the rules every following game is played with
+    layout: BoardLayout, // the board every following game is played on
+    seed: Option<usize>, // replay this game if set
 }
 
 impl Game {
     pub fn new() -> Game {
-        let opengl = OpenGL::V3_2;
-        let window: GlutinWindow = WindowSettings::new(
-            "Rust Monopoly",
-            [WINDOW_WIDTH as u32, WINDOW_HEIGHT as u32]
-        )
-        .opengl(opengl)
-        .exit_on_esc(true)
-        .build()
-        .unwrap();
         Game {
-            main_window: window,
-            gl: GlGraphics::new(opengl),
             board: Board::new(),
             game_state: GameState::GameGUISetup,
             turn_state: TurnState::StartTurn,
             turn_command: None,
             key_queue: Vec::new(),
+            prop_command: None,
+            trade_draft: None,
+            house_auction: false,
+            auto_income_tax: false,
+            debug: false,
+            rules: RuleSet::new(),
+            layout: BoardLayout::new(),
+            seed: None,
         }
     }
     
+    /// If set, players are not asked how to pay income tax; the
+    /// cheaper option is paid for them
+    pub fn set_auto_income_tax(&mut self, auto_income_tax: bool) {
+        self.auto_income_tax = auto_income_tax;
+    }
+    
+    /// In debug mode the board is audited after every turn, and any
+    /// problems found are printed
+    pub fn set_debug(&mut self, debug: bool) {
+        self.debug = debug;
+    }
+    
+    /// Play every following game with these rules
+    pub fn set_rules(&mut self, rules: RuleSet) {
+        self.rules = rules;
+    }
+    
+    /// Play every following game on this board
+    pub fn set_layout(&mut self, layout: BoardLayout) {
+        self.layout = layout;
+    }
+    
+    /// Play every following game with this seed, so that a game can
+    /// be replayed exactly from the seed printed at setup
+    pub fn set_seed(&mut self, seed: Option<usize>) {
+        self.seed = seed;
+    }
+    
     fn reset_state(&mut self) {
-        self.board = Board::new();
+        self.board = match self.seed {
+            Some(seed) => Board::with_random(Random::from_seed(seed)),
+            None => Board::new(),
+        };
+        self.board.set_rules(self.rules.clone());
+        self.board.set_layout(self.layout.clone());
         self.game_state = GameState::GameGUISetup;
         self.turn_state = TurnState::StartTurn;
         self.turn_command = None;
         self.key_queue = Vec::new();
+        self.prop_command = None;
+        self.trade_draft = None;
+        self.house_auction = false;
+    }
+    
+    pub fn get_board(&self) -> &Board {
+        &self.board
+    }
+    
+    pub fn get_board_mut(&mut self) -> &mut Board {
+        &mut self.board
+    }
+    
+    pub fn get_game_state(&self) -> GameState {
+        self.game_state.clone()
+    }
+    
+    pub fn get_turn_state(&self) -> TurnState {
+        self.turn_state.clone()
+    }
+    
+    /// Called by the frontend once it is ready to draw; the next
+    /// update() will prompt for players on the console.
+    pub fn begin_setup(&mut self) {
+        if self.game_state == GameState::GameGUISetup {
+            self.game_state = GameState::GameStateSetup;
+        }
+    }
+    
+    /// Set up a game without the console prompts. Players take turns
+    /// in the order given.
+    pub fn setup_players(&mut self, players: Vec<(String, [f32; 4])>) {
+        self.reset_state();
+        self.board.reset_spaces();
+        
+        for (name, color) in players {
+            let go = self.board.get_go();
+            let player = Rc::new(RefCell::new(
+                Player::new(name, go.clone(), color)));
+            go.borrow_mut().add_player(player.clone());
+            self.board.add_player(player);
+        }
+        self.game_state = GameState::GameRun;
     }
     
     pub fn setup_game(&mut self) {
@@ -146,7 +251,7 @@ impl Game {
         println!("Welcome to Monopoly!");
         print!("How many players today? ");
         
-        let num_players = get_num_players();
+        let num_players = get_num_players(self.board.get_rules().get_max_num_players());
         let mut available_colors = vec![true, true, true, true, true, true];
         let colors = vec![RED, ORANGE, YELLOW, GREEN, BLUE, PURPLE];
         
@@ -184,13 +289,13 @@ impl Game {
                     print!("That color is already chosen! Pick another color: ");
                 }
             }
-            let mut n = get_dice_roll_12();
+            let mut n = self.board.get_random_mut().roll_dice().get_total();
             while turns_to_players.contains_key(&n) {
-                n = get_dice_roll_12();
+                n = self.board.get_random_mut().roll_dice().get_total();
             }
             
             
-            let go = self.board.get_space(GO);
+            let go = self.board.get_go();
             let player = Rc::new(RefCell::new(
                 Player::new(name.trim().to_string(), 
                             go.clone(), 
@@ -203,26 +308,16 @@ impl Game {
             self.board.add_player(player);
         }
         
-        println!("Game setup complete.\n");
+        println!("Game setup complete. Game seed: {}\n",
+                 self.board.get_seed());
         
     }
     
-    /// Clear the screen
-    fn clear(&mut self, args: &RenderArgs) {
-        use graphics::*;
-        let ref mut gl = self.gl;
-        const WHITE: [f32; 4] = [1.0; 4];
-    
-        gl.draw(args.viewport(), |c, gl| {
-            clear(WHITE, gl);
-        });
-    }
-
-    // Update the game state based on the key pressed
-    fn handle_key_input(&mut self, key: keyboard::Key) {
+    /// Update the game state based on the key pressed
+    pub fn handle_key_input(&mut self, key: char) {
         //println!("Key pressed = {:?}", key);
-        match key {
-            Key::R => {
+        match key.to_ascii_lowercase() {
+            'r' => {
                 if self.turn_state == TurnState::WaitingForCommand {
                     self.turn_state = TurnState::ExecutingCommand;
                     self.turn_command = Some(TurnCommand::Roll);
@@ -230,28 +325,79 @@ impl Game {
                     self.turn_command = Some(TurnCommand::Roll);
                 }
             },
-            Key::C => {
+            'c' => {
                 if self.turn_state == TurnState::InJail {
-                    unimplemented!();
+                    self.turn_command = Some(TurnCommand::UseJailCard);
+                } else if self.turn_state == TurnState::TradeMenu {
+                    self.enter_trade_value(TradeInput::OfferCash);
+                } else if self.turn_state == TurnState::TradeResponse {
+                    self.trade_draft = self.board.get_trade().map(|trade| trade.reversed());
+                    self.turn_state = TurnState::StartTradeMenu;
                 }
             },
-            Key::Q => {
+            'q' => {
                 if self.turn_state == TurnState::WaitingForCommand {
                     self.turn_state = TurnState::ExecutingCommand;
                     self.turn_command = Some(TurnCommand::Quit);
+                } else if self.turn_state == TurnState::TradeMenu {
+                    println!("Trade cancelled.");
+                    self.trade_draft = None;
+                    self.after_trade();
+                }
+            },
+            't' => {
+                if self.turn_state == TurnState::WaitingForCommand {
+                    self.turn_state = TurnState::ExecutingCommand;
+                    self.turn_command = Some(TurnCommand::Trade);
+                }
+            },
+            'o' => {
+                if self.turn_state == TurnState::TradeMenu {
+                    self.enter_trade_value(TradeInput::OfferProperty);
+                }
+            },
+            'w' => {
+                if self.turn_state == TurnState::TradeMenu {
+                    self.enter_trade_value(TradeInput::RequestProperty);
+                }
+            },
+            'd' => {
+                if self.turn_state == TurnState::TradeMenu {
+                    self.enter_trade_value(TradeInput::RequestCash);
+                }
+            },
+            'j' => {
+                if self.turn_state == TurnState::TradeMenu {
+                    self.add_trade_jail_card(true);
+                }
+            },
+            'k' => {
+                if self.turn_state == TurnState::TradeMenu {
+                    self.add_trade_jail_card(false);
                 }
             },
-            Key::B => {
+            'b' => {
                 if self.turn_state == TurnState::ConfirmBuySellHouseHotel {
                     self.turn_state = TurnState::BuyHouseHotel;
                 }
             },
-            Key::S => {
+            's' => {
                 if self.turn_state == TurnState::ConfirmBuySellHouseHotel {
                     self.turn_state = TurnState::SellHouseHotel;
+                } else if self.turn_state == TurnState::TradeMenu {
+                    if let Some(trade) = self.trade_draft.clone() {
+                        let sent = if self.board.get_trade().is_some() {
+                            self.counter_trade(trade)
+                        } else {
+                            self.propose_trade(trade)
+                        };
+                        if !sent {
+                            self.turn_state = TurnState::StartTradeMenu;
+                        }
+                    }
                 }
             },
-            Key::Y => {
+            'y' => {
                 match self.game_state.clone() {
                     GameState::GameOver => {
                         self.game_state = GameState::GameStateSetup;
@@ -262,33 +408,52 @@ impl Game {
                         match self.turn_state.clone() {
                             TurnState::ConfirmQuit => {
                                 println!("Goodbye!");
-                                process::exit(0);
+                                self.game_state = GameState::GameQuit;
                             },
                             TurnState::ConfirmPurchase(ref mut prop) => {
                                 self.board.on_purchase(prop.clone());
                                 self.turn_state = TurnState::AfterCommand;
                                 self.turn_command = None;
                             },
+                            TurnState::TradeResponse => {
+                                self.accept_trade();
+                            },
+                            TurnState::ConfirmGiveUp => {
+                                self.board.give_up();
+                                self.turn_state = TurnState::AfterCommand;
+                                self.turn_command = None;
+                            },
                             _ => (),
                         };
                     },
                     _ => (),
                 };
             },
-            Key::N => {
+            'n' => {
                 match self.game_state.clone() {
                     GameState::GameOver => {
-                        process::exit(0);
+                        self.game_state = GameState::GameQuit;
                     },
                     GameState::GameRun => {
-                        match self.turn_state {
+                        match self.turn_state.clone() {
                             TurnState::ConfirmQuit => {
                                 self.turn_state = TurnState::StartWaitingForCommand;
                                 self.turn_command = None;
                             },
-                            TurnState::ConfirmPurchase(_) => {
-                                self.turn_state = TurnState::AfterCommand;
+                            TurnState::ConfirmPurchase(ref prop) => {
+                                let prop = prop.clone();
                                 self.turn_command = None;
+                                if self.board.get_rules().has_auctions() {
+                                    self.start_auction(prop);
+                                } else {
+                                    self.turn_state = TurnState::AfterCommand;
+                                }
+                            },
+                            TurnState::TradeResponse => {
+                                self.reject_trade();
+                            },
+                            TurnState::ConfirmGiveUp => {
+                                self.turn_state = TurnState::StartRaiseFunds;
                             },
                             _ => (),
                         };
@@ -296,52 +461,342 @@ impl Game {
                     _ => (),
                 };
             },
-            Key::A => {
+            'a' => {
                 if self.turn_state == TurnState::WaitingForCommand {
                     self.turn_state = TurnState::ExecutingCommand;
                     self.turn_command = Some(TurnCommand::Assets);
                 }
             },
-            Key::P => {
-                if self.turn_state == TurnState::InJail {
-                    let player = self.board.get_current_player();
-                    let cash = player.borrow().get_cash();
-                    if cash >= 50 {
-                        self.turn_command = Some(TurnCommand::PayJailFine);
-                    } else {
-                        println!("You don't have enough money! Choose another option.");
-                    }
+            'l' => {
+                if self.turn_state == TurnState::WaitingForCommand {
+                    self.turn_state = TurnState::ExecutingCommand;
+                    self.turn_command = Some(TurnCommand::Statement);
                 }
             },
-            Key::H => {
+            'u' => {
                 if self.turn_state == TurnState::WaitingForCommand {
+                    self.turn_state = TurnState::ExecutingCommand;
+                    self.turn_command = Some(TurnCommand::Audit);
+                }
+            },
+            'p' => {
+                if self.turn_state == TurnState::InJail {
+                    self.turn_command = Some(TurnCommand::PayJailFine);
+                } else if self.turn_state == TurnState::EnterBid {
+                    self.pass_bid();
+                } else if self.turn_state == TurnState::ConfirmIncomeTax {
+                    self.pay_income_tax(IncomeTaxChoice::Percent);
+                }
+            },
+            'f' => {
+                if self.turn_state == TurnState::ConfirmIncomeTax {
+                    self.pay_income_tax(IncomeTaxChoice::Flat);
+                }
+            },
+            'h' => {
+                if self.turn_state == TurnState::WaitingForCommand ||
+                   self.turn_state == TurnState::RaiseFunds {
                     self.turn_state = TurnState::ExecutingCommand;
                     self.turn_command = Some(TurnCommand::HouseHotel);
                 }
             },
-            Key::D0 |
-            Key::D1 |
-            Key::D2 |
-            Key::D3 |
-            Key::D4 |
-            Key::D5 |
-            Key::D6 |
-            Key::D7 |
-            Key::D8 |
-            Key::D9   => {
-                if self.turn_state == TurnState::EnterPropIndex {
-                    self.key_queue.push(key as u8);
+            'g' => {
+                if self.turn_state == TurnState::RaiseFunds {
+                    println!("Are you sure you want to give up and go bankrupt? ");
+                    self.turn_state = TurnState::ConfirmGiveUp;
                 }
             },
-            Key::Return => {
-                if self.turn_state == TurnState::EnterPropIndex {
-                    self.turn_state = TurnState::ValidatePropIndex;
+            'm' => {
+                if self.turn_state == TurnState::WaitingForCommand ||
+                   self.turn_state == TurnState::RaiseFunds {
+                    self.turn_state = TurnState::ExecutingCommand;
+                    self.turn_command = Some(TurnCommand::Mortgage);
+                }
+            },
+            '0' ..= '9' => {
+                match self.turn_state {
+                    TurnState::EnterPropIndex |
+                    TurnState::EnterBid |
+                    TurnState::EnterTradeValue(_) => self.key_queue.push(key as u8),
+                    _ => (),
+                }
+            },
+            '\n' => {
+                match self.turn_state {
+                    TurnState::EnterPropIndex =>
+                        self.turn_state = TurnState::ValidatePropIndex,
+                    TurnState::EnterBid =>
+                        self.turn_state = TurnState::ValidateBid,
+                    TurnState::EnterTradeValue(input) =>
+                        self.turn_state = TurnState::ValidateTradeValue(input),
+                    _ => (),
                 }
             },
             _ => self.turn_command = None,
         }
     }
     
+    /// Put the property up for auction; the current player's turn
+    /// carries on once the auction is over
+    pub fn start_auction(&mut self, prop: Rc<RefCell<Property>>) {
+        self.board.start_auction(prop);
+        self.after_bid();
+    }
+    
+    /// A property the bank took back from a bankrupt player, which
+    /// goes up for auction while the game is still running
+    fn take_returned_property(&mut self) -> Option<Rc<RefCell<Property>>> {
+        if self.game_state == GameState::GameRun {
+            self.board.take_returned_property()
+        } else {
+            None
+        }
+    }
+    
+    /// The auction currently being held, if any
+    pub fn get_auction(&self) -> Option<&Auction> {
+        self.board.get_auction()
+    }
+    
+    /// The current bidder bids the given amount. Frontends and bots
+    /// both bid through here. Returns false if no bid is being waited
+    /// on or the bid is refused.
+    pub fn place_bid(&mut self, amount: i32) -> bool {
+        if !self.is_bidding() {
+            return false;
+        }
+        let accepted = self.board.bid(amount);
+        self.after_bid();
+        accepted
+    }
+    
+    /// The current bidder drops out of the auction
+    pub fn pass_bid(&mut self) {
+        if !self.is_bidding() {
+            return;
+        }
+        self.board.pass_bid();
+        self.after_bid();
+    }
+    
+    fn is_bidding(&self) -> bool {
+        match self.turn_state {
+            TurnState::StartAuctionBid |
+            TurnState::EnterBid |
+            TurnState::ValidateBid => self.board.get_auction().is_some(),
+            _ => false,
+        }
+    }
+    
+    fn after_bid(&mut self) {
+        if self.board.get_auction().is_some() {
+            self.turn_state = TurnState::StartAuctionBid;
+        } else if self.house_auction {
+            self.house_auction = false;
+            self.after_prop_command();
+        } else {
+            self.turn_state = TurnState::AfterCommand;
+            self.turn_command = None;
+        }
+    }
+    
+    /// Back to the player's command prompt after a property command,
+    /// or to raising funds if that is what the player was doing
+    fn after_prop_command(&mut self) {
+        if self.board.get_debtor().is_some() {
+            self.turn_state = TurnState::StartRaiseFunds;
+        } else {
+            self.turn_state = TurnState::StartWaitingForCommand;
+        }
+        self.turn_command = None;
+    }
+    
+    /// The current player pays income tax the way they chose.
+    /// Returns false if they are not being asked about income tax.
+    pub fn pay_income_tax(&mut self, choice: IncomeTaxChoice) -> bool {
+        if self.turn_state != TurnState::ConfirmIncomeTax {
+            return false;
+        }
+        let tax = match choice {
+            IncomeTaxChoice::Flat => self.board.get_rules().get_income_tax_amt(),
+            IncomeTaxChoice::Percent =>
+                self.board.get_income_tax_percent_amt(self.board.get_rules().get_income_tax_percent()),
+        };
+        self.board.on_land_income_tax(tax);
+        self.turn_state = TurnState::AfterCommand;
+        self.turn_command = None;
+        true
+    }
+    
+    /// The trade waiting for an answer, if any
+    pub fn get_trade(&self) -> Option<&Trade> {
+        self.board.get_trade()
+    }
+    
+    /// The current player proposes a trade. Frontends and bots both
+    /// trade through here. Returns false if the current player cannot
+    /// trade now or the trade is not allowed.
+    pub fn propose_trade(&mut self, trade: Trade) -> bool {
+        let may_trade = match self.turn_state {
+            TurnState::WaitingForCommand |
+            TurnState::StartTradeMenu |
+            TurnState::TradeMenu => self.board.get_trade().is_none(),
+            _ => false,
+        };
+        if !may_trade || !Rc::ptr_eq(&trade.get_proposer(), &self.board.get_current_player()) {
+            return false;
+        }
+        if !self.board.propose_trade(trade) {
+            return false;
+        }
+        self.trade_draft = None;
+        self.turn_state = TurnState::StartTradeResponse;
+        true
+    }
+    
+    /// The receiver of the waiting trade accepts it
+    pub fn accept_trade(&mut self) -> bool {
+        if self.board.get_trade().is_none() {
+            return false;
+        }
+        let accepted = self.board.accept_trade();
+        self.after_trade();
+        accepted
+    }
+    
+    /// The receiver of the waiting trade rejects it
+    pub fn reject_trade(&mut self) {
+        if self.board.get_trade().is_none() {
+            return;
+        }
+        self.board.reject_trade();
+        self.after_trade();
+    }
+    
+    /// The receiver of the waiting trade answers it with their own.
+    /// Returns false if the counter is not allowed.
+    pub fn counter_trade(&mut self, trade: Trade) -> bool {
+        if !self.board.counter_trade(trade) {
+            return false;
+        }
+        self.trade_draft = None;
+        self.turn_state = TurnState::StartTradeResponse;
+        true
+    }
+    
+    /// The current player carries on with their turn, unless a trade
+    /// is still waiting for an answer
+    fn after_trade(&mut self) {
+        if self.board.get_trade().is_some() {
+            self.turn_state = TurnState::StartTradeResponse;
+        } else {
+            self.turn_state = TurnState::StartWaitingForCommand;
+            self.turn_command = None;
+        }
+    }
+    
+    /// Every other player still in the game, whom the current player
+    /// may trade with
+    fn get_trade_partners(&self) -> Vec<Rc<RefCell<Player>>> {
+        let current = self.board.get_current_player();
+        self.board.get_players().iter()
+            .filter(|player| !Rc::ptr_eq(player, &current) &&
+                             !player.borrow().is_bankrupt())
+            .cloned()
+            .collect()
+    }
+    
+    /// Prompt for a value to add to the trade being put together
+    fn enter_trade_value(&mut self, input: TradeInput) {
+        let trade = match self.trade_draft {
+            Some(ref trade) => trade,
+            None => return,
+        };
+        match input {
+            TradeInput::Partner => (),
+            TradeInput::OfferProperty | TradeInput::RequestProperty => {
+                let owner = if input == TradeInput::OfferProperty {
+                    trade.get_proposer()
+                } else {
+                    trade.get_receiver()
+                };
+                println!("Enter index of {}'s property, then press ENTER:",
+                         owner.borrow().get_name());
+                for (index, prop) in owner.borrow().get_properties().iter().enumerate() {
+                    println!("{}: {}", index, prop.borrow().get_name());
+                }
+            },
+            TradeInput::OfferCash => {
+                println!("Enter the cash {} gives, then press ENTER:",
+                         trade.get_proposer().borrow().get_name());
+            },
+            TradeInput::RequestCash => {
+                println!("Enter the cash {} gives, then press ENTER:",
+                         trade.get_receiver().borrow().get_name());
+            },
+        }
+        self.key_queue = Vec::new();
+        self.turn_state = TurnState::EnterTradeValue(input);
+    }
+    
+    /// Add the value typed in to the trade being put together; for
+    /// the partner and properties it is an index into the list shown
+    fn add_trade_value(&mut self, input: TradeInput, value: usize) {
+        if input == TradeInput::Partner {
+            let partners = self.get_trade_partners();
+            if value >= partners.len() {
+                println!("Index must be within range!");
+            } else {
+                self.trade_draft = Some(Trade::new(self.board.get_current_player(),
+                                                   partners[value].clone()));
+            }
+            return;
+        }
+        if let Some(ref mut trade) = self.trade_draft {
+            match input {
+                TradeInput::Partner => unreachable!(),
+                TradeInput::OfferProperty | TradeInput::RequestProperty => {
+                    let owner = if input == TradeInput::OfferProperty {
+                        trade.get_proposer()
+                    } else {
+                        trade.get_receiver()
+                    };
+                    let properties = owner.borrow().get_properties().clone();
+                    if value >= properties.len() {
+                        println!("Index must be within range!");
+                    } else if input == TradeInput::OfferProperty {
+                        trade.get_offered_mut().add_property(properties[value].clone());
+                    } else {
+                        trade.get_requested_mut().add_property(properties[value].clone());
+                    }
+                },
+                TradeInput::OfferCash => trade.get_offered_mut().set_cash(value as i32),
+                TradeInput::RequestCash => trade.get_requested_mut().set_cash(value as i32),
+            }
+        }
+    }
+    
+    /// Add one more Get Out of Jail Free card to the proposer's side
+    /// of the trade being put together (or to the receiver's side)
+    fn add_trade_jail_card(&mut self, offered: bool) {
+        if let Some(ref mut trade) = self.trade_draft {
+            let giver = if offered { trade.get_proposer() } else { trade.get_receiver() };
+            let side = if offered { trade.get_offered_mut() } else { trade.get_requested_mut() };
+            let held = giver.borrow().get_jail_cards().clone();
+            let card = [JailCard::Chance, JailCard::CommunityChest].iter().cloned()
+                .find(|card| {
+                    held.iter().filter(|c| *c == card).count() >
+                        side.get_jail_cards().iter().filter(|c| *c == card).count()
+                });
+            match card {
+                Some(card) => side.add_jail_card(card),
+                None => println!("{} has no more Get Out of Jail Free cards!",
+                                 giver.borrow().get_name()),
+            }
+        }
+        self.turn_state = TurnState::StartTradeMenu;
+    }
+    
     pub fn handle_land_space(&mut self, space: Rc<RefCell<Space>>) {
         let t = { // due to Rust's pedantic borrowing system...
             let space = space.borrow();
@@ -350,7 +805,7 @@ impl Game {
             
         match t {
             SpaceEnum::Prop(_) => unreachable!(),
-            SpaceEnum::Go => self.board.on_land_go(GO_SALARY),
+            SpaceEnum::Go => self.board.on_land_go(self.board.get_rules().get_go_salary()),
             SpaceEnum::Chance => {
                 let action = self.board.on_land_chance();
                 //self.turn_state = TurnState::ExecutingCommand;
@@ -358,28 +813,67 @@ impl Game {
                 println!("turn_command = {:?}", self.turn_command);
                 self.handle_land(action);
             },
-            SpaceEnum::CommunityChest => self.board.on_land_comm_chest(),
+            SpaceEnum::CommunityChest => {
+                let action = self.board.on_land_comm_chest();
+                self.handle_land(action);
+            },
             SpaceEnum::Jail => self.board.on_land_jail(),
             SpaceEnum::FreeParking => self.board.on_land_free_parking(),
-            SpaceEnum::GoToJail => self.board.on_land_go_to_jail(GO_SALARY),
-            SpaceEnum::IncomeTax => self.board.on_land_income_tax(INCOME_TAX_AMT),
-            SpaceEnum::LuxuryTax => self.board.on_land_luxury_tax(LUXURY_TAX_AMT),
+            SpaceEnum::GoToJail => self.board.on_land_go_to_jail(self.board.get_rules().get_go_salary()),
+            SpaceEnum::IncomeTax => {
+                let flat_amt = self.board.get_rules().get_income_tax_amt();
+                let percent = self.board.get_rules().get_income_tax_percent();
+                let percent_amt = self.board.get_income_tax_percent_amt(percent);
+                if self.auto_income_tax {
+                    let choice = if percent_amt < flat_amt {
+                        IncomeTaxChoice::Percent
+                    } else {
+                        IncomeTaxChoice::Flat
+                    };
+                    self.turn_state = TurnState::ConfirmIncomeTax;
+                    self.pay_income_tax(choice);
+                } else {
+                    println!("Income tax! Pay ${}(F) or {}% of your net worth, ${}(P)?",
+                             flat_amt, percent, percent_amt);
+                    self.turn_state = TurnState::ConfirmIncomeTax;
+                }
+            },
+            SpaceEnum::LuxuryTax => self.board.on_land_luxury_tax(self.board.get_rules().get_luxury_tax_amt()),
         }
     }
     
     pub fn handle_land(&mut self, action: LandAction) {
         match action {
-            LandAction::Rent(ref prop) => {
+            LandAction::Rent(ref prop, ref modifier) => {
                 let owner = {
                     let property = prop.borrow();
                     property.get_owner().clone()
                 };    
                 
+                if prop.borrow().is_mortgaged() {
+                    println!("{} is owned by {} but mortgaged. No rent is due.",
+                             prop.borrow().get_name(),
+                             owner.borrow().get_name());
+                    self.turn_state = TurnState::AfterCommand;
+                    self.turn_command = None;
+                    return;
+                }
+                if self.board.is_owner_in_jail(prop) {
+                    println!("{} is owned by {}, who is in jail. No rent is due.",
+                             prop.borrow().get_name(),
+                             owner.borrow().get_name());
+                    self.turn_state = TurnState::AfterCommand;
+                    self.turn_command = None;
+                    return;
+                }
                 println!("{} is owned by {}. Pay rent of ${}!", 
                          prop.borrow().get_name(),
                          owner.borrow().get_name(), 
-                         self.board.get_rent(prop.clone()));
-                self.board.on_rent_collected(owner.clone(), prop.clone());
+                         self.board.get_rent(prop.clone(), modifier));
+                if let Some(explanation) = self.board.explain_rent(prop.clone(), modifier) {
+                    println!("(Rent is {}.)", explanation);
+                }
+                self.board.on_rent_collected(owner.clone(), prop.clone(), modifier);
                 
                 self.turn_state = TurnState::AfterCommand;
                 self.turn_command = None;
@@ -394,8 +888,12 @@ impl Game {
                 println!("You don't have enough money to purchase {}!",
                             prop.borrow().get_name());
                             
-                self.turn_state = TurnState::AfterCommand;
                 self.turn_command = None;
+                if self.board.get_rules().has_auctions() {
+                    self.start_auction(prop.clone());
+                } else {
+                    self.turn_state = TurnState::AfterCommand;
+                }
             },
             LandAction::MightPurchase(ref prop) => {
                 println!("{} is not owned. Would you like to buy it for ${}?",
@@ -410,293 +908,397 @@ impl Game {
                 self.turn_command = None;
                 self.handle_land_space(space.clone());
             },
+            LandAction::GoToJail => {
+                self.board.on_land_go_to_jail(self.board.get_rules().get_go_salary());
+                self.turn_state = TurnState::AfterCommand;
+                self.turn_command = None;
+            },
+            LandAction::Nothing => {
+                self.turn_state = TurnState::AfterCommand;
+                self.turn_command = None;
+            },
         }
     }
     
-    /// The main event loop
-    pub fn run(&mut self) {
-        let mut events = self.main_window.events();
-        while let Some(e) = events.next(&mut self.main_window) {
-            //println!("Updated game state");
-            match self.game_state {
-                GameState::GameGUISetup => {},
+    /// Advance the game state by one step. Frontends call this once
+    /// per event; it does nothing while waiting for input.
+    pub fn update(&mut self) {
+        match self.game_state {
+            GameState::GameGUISetup => {},
+            GameState::GameQuit => {},
+        
+            GameState::GameStateSetup => {
+                // TODO: clear game window
+                self.setup_game();
+                self.game_state = GameState::GameRun;
+            },
             
-                GameState::GameStateSetup => {
-                    // TODO: clear game window
-                    self.setup_game();
-                    self.game_state = GameState::GameRun;
-                },
-                
-                GameState::GameRun => {
-                    match self.turn_state {
-                        TurnState::StartTurn => {
-                            //print!("{}[2J", 27 as char); // clear screen
-                            self.board.start_turn();
-                            let player = self.board.get_current_player();
-                            let player = player.borrow();
-                            if player.is_in_jail() {
-                                println!("You are in jail! You can try to roll doubles(R) or \
-                                    pay $50(P).");
-                                self.turn_state = TurnState::InJail;
-                            } else {
-                                self.turn_state = TurnState::StartWaitingForCommand;
+            GameState::GameRun => {
+                match self.turn_state {
+                    TurnState::StartTurn => {
+                        //print!("{}[2J", 27 as char); // clear screen
+                        self.board.start_turn();
+                        let player = self.board.get_current_player();
+                        let player = player.borrow();
+                        if player.is_in_jail() {
+                            println!("You are in jail! You can try to roll doubles(R) or \
+                                pay ${}(P).", self.board.get_rules().get_jail_fine());
+                            if player.has_jail_card() {
+                                println!("You can also use a Get Out of Jail Free card(C).");
                             }
-                        },
-                        TurnState::StartWaitingForCommand => {
-                            println!("");
-                            println!("**************************************************");
-                            println!("Please enter a command in the drawing window:");
-                            println!("roll(R)");
-                            println!("quit(Q)");
-                            println!("assets(A)");
-                            println!("houses(H)");
-                            println!("**************************************************");
-                            println!(">> ");
-                            self.turn_state = TurnState::WaitingForCommand;
-                        },
-                        TurnState::WaitingForCommand => {
-                            // do nothing while waiting
-                        },
-                        TurnState::ExecutingCommand => {
-                            if let Some(command) = self.turn_command.clone() {;
-                                match command {
-                                    TurnCommand::Roll => {  
-                                        let action = self.board.roll_and_land();
-                                        self.handle_land(action);
-                                    },
-                                    
-                                    TurnCommand::Quit => {
-                                        println!("Are you sure you want to quit? ");
-                                        self.turn_state = TurnState::ConfirmQuit;
-                                    },
-                                    
-                                    TurnCommand::Assets => {
-                                        self.board.print_player_assets();
-                                        self.turn_state = TurnState::StartWaitingForCommand;
-                                    },
-                                    
-                                    TurnCommand::HouseHotel => {
-                                        let player = self.board.get_current_player();
-                                        let monopolies = player.borrow().get_monopolies();
-                                        if monopolies.len() == 0 {
-                                            println!("You have no monopolies on which you \
-                                                      can place houses/hotels.");
-                                            self.turn_state = TurnState::StartWaitingForCommand;
-                                        } else {
-                                            println!("Enter property index, then press ENTER:");
-                                            let mut index = 0;
-                                            for prop in monopolies {
-                                                println!("{}: {}", index, prop.borrow().get_name());
-                                                index += 1;
+                            self.turn_state = TurnState::InJail;
+                        } else {
+                            self.turn_state = TurnState::StartWaitingForCommand;
+                        }
+                    },
+                    TurnState::StartWaitingForCommand => {
+                        println!("");
+                        println!("**************************************************");
+                        println!("Please enter a command in the drawing window:");
+                        println!("roll(R)");
+                        println!("quit(Q)");
+                        println!("assets(A)");
+                        println!("statement(L)");
+                        println!("audit(U)");
+                        println!("houses(H)");
+                        println!("mortgage(M)");
+                        println!("trade(T)");
+                        println!("**************************************************");
+                        println!(">> ");
+                        self.turn_state = TurnState::WaitingForCommand;
+                    },
+                    TurnState::WaitingForCommand => {
+                        // do nothing while waiting
+                    },
+                    TurnState::ExecutingCommand => {
+                        if let Some(command) = self.turn_command.clone() {
+                            match command {
+                                TurnCommand::Roll => {  
+                                    let action = self.board.roll_and_land();
+                                    self.handle_land(action);
+                                },
+                                
+                                TurnCommand::Quit => {
+                                    println!("Are you sure you want to quit? ");
+                                    self.turn_state = TurnState::ConfirmQuit;
+                                },
+                                
+                                TurnCommand::Assets => {
+                                    self.board.print_player_assets();
+                                    self.turn_state = TurnState::StartWaitingForCommand;
+                                },
+                                
+                                TurnCommand::Statement => {
+                                    self.board.print_statement();
+                                    self.turn_state = TurnState::StartWaitingForCommand;
+                                },
+                                
+                                TurnCommand::Audit => {
+                                    print_audit(&self.board);
+                                    self.turn_state = TurnState::StartWaitingForCommand;
+                                },
+                                
+                                TurnCommand::HouseHotel => {
+                                    let player = self.board.get_acting_player();
+                                    let monopolies = self.board.get_monopolies(&player);
+                                    if monopolies.is_empty() {
+                                        println!("You have no monopolies on which you \
+                                                  can place houses/hotels.");
+                                        self.after_prop_command();
+                                    } else {
+                                        println!("Enter property index, then press ENTER:");
+                                        let mut index = 0;
+                                        for prop in monopolies {
+                                            let prop = prop.borrow();
+                                            match prop.get_num_houses() {
+                                                _ if prop.has_hotel() =>
+                                                    println!("{}: {} (hotel)", index, prop.get_name()),
+                                                1 => println!("{}: {} (1 house)", index, prop.get_name()),
+                                                n => println!("{}: {} ({} houses)", index, prop.get_name(), n),
                                             }
-                                            self.key_queue = Vec::new();
-                                            self.turn_state = TurnState::EnterPropIndex;
+                                            index += 1;
                                         }
-                                    },
-                                    
-                                    _ => (),
-                                };   
-                                self.turn_command = None;
-                            };
-                        },
-                        
-                        TurnState::InJail => {
-                            if let Some(command) = self.turn_command.clone() {;
-                                match command {
-                                    TurnCommand::PayJailFine => {  
-                                        let player = self.board.get_current_player();
-                                        println!("{} paid $50.", player.borrow().get_name());
-                                        player.borrow_mut().tax(50);
-                                        player.borrow_mut().unjail();
+                                        self.board.print_building_choices(&player);
+                                        self.key_queue = Vec::new();
+                                        self.prop_command = Some(TurnCommand::HouseHotel);
+                                        self.turn_state = TurnState::EnterPropIndex;
+                                    }
+                                },
+                                
+                                TurnCommand::Trade => {
+                                    let partners = self.get_trade_partners();
+                                    if partners.is_empty() {
+                                        println!("There is no one to trade with.");
+                                        self.after_prop_command();
+                                    } else {
+                                        println!("Enter index of player to trade with, \
+                                                  then press ENTER:");
+                                        for (index, player) in partners.iter().enumerate() {
+                                            println!("{}: {}", index, player.borrow().get_name());
+                                        }
+                                        self.key_queue = Vec::new();
+                                        self.turn_state = TurnState::EnterTradeValue(TradeInput::Partner);
+                                    }
+                                },
+                                
+                                TurnCommand::Mortgage => {
+                                    let player = self.board.get_acting_player();
+                                    let properties = player.borrow().get_properties().clone();
+                                    if properties.is_empty() {
+                                        println!("You have no properties to mortgage.");
+                                        self.after_prop_command();
+                                    } else {
+                                        println!("Enter index of property to mortgage or \
+                                                  unmortgage, then press ENTER:");
+                                        for (index, prop) in properties.iter().enumerate() {
+                                            let prop = prop.borrow();
+                                            if prop.is_mortgaged() {
+                                                println!("{}: {} (mortgaged; lift for ${})",
+                                                         index, prop.get_name(),
+                                                         prop.get_unmortgage_cost());
+                                            } else {
+                                                println!("{}: {} (mortgage for ${})",
+                                                         index, prop.get_name(),
+                                                         prop.get_mortgage_value());
+                                            }
+                                        }
+                                        self.key_queue = Vec::new();
+                                        self.prop_command = Some(TurnCommand::Mortgage);
+                                        self.turn_state = TurnState::EnterPropIndex;
+                                    }
+                                },
+                                
+                                _ => (),
+                            };   
+                            self.turn_command = None;
+                        };
+                    },
+                    
+                    TurnState::InJail => {
+                        if let Some(command) = self.turn_command.clone() {
+                            match command {
+                                TurnCommand::PayJailFine => {  
+                                    if self.board.pay_jail_fine(self.board.get_rules().get_jail_fine()) {
                                         self.turn_state = TurnState::StartWaitingForCommand;
-                                        self.turn_command = None;
-                                    },
-                                    
-                                    TurnCommand::Roll => {
-                                        let player = self.board.get_current_player();
-                                        let first = get_dice_roll_6();
-                                        let second = get_dice_roll_6();
-                                        if first == second {
-                                            println!("{} rolled doubles and is now free!", player.borrow().get_name());
-                                            player.borrow_mut().unjail();
-                                            self.turn_state = TurnState::StartWaitingForCommand;
-                                            self.turn_command = None;
-                                        } else {
-                                            println!("{} did not roll doubles and remains in jail!", player.borrow().get_name());
+                                    }
+                                    self.turn_command = None;
+                                },
+                                
+                                TurnCommand::Roll => {
+                                    match self.board.roll_in_jail(self.board.get_rules().get_jail_fine()) {
+                                        Some(action) => self.handle_land(action),
+                                        None => {
                                             self.turn_state = TurnState::AfterCommand;
                                             self.turn_command = None;
-                                        }
-                                    },
-                                    
-                                    TurnCommand::UseJailCard => {
-                                        let player = self.board.get_current_player();
-                                        println!("{} used a GOOJFC.", player.borrow().get_name());
-                                        player.borrow_mut().unjail();
+                                        },
+                                    }
+                                },
+                                
+                                TurnCommand::UseJailCard => {
+                                    if self.board.use_jail_card() {
                                         self.turn_state = TurnState::StartWaitingForCommand;
-                                        self.turn_command = None;
-                                    },
-                                    
-                                    _ => (),
-                                };   
-                                self.turn_command = None;
-                            };
-                        },
-                        
-                        TurnState::AfterCommand => {
-                            self.board.handle_bankruptcy();
-                            if self.board.get_num_remaining_players() == 1 {
-                                self.game_state = GameState::GameOver;
+                                    }
+                                    self.turn_command = None;
+                                },
+                                
+                                _ => (),
+                            };   
+                            self.turn_command = None;
+                        };
+                    },
+                    
+                    TurnState::AfterCommand => {
+                        self.board.handle_bankruptcy();
+                        if self.board.get_num_remaining_players() == 1 {
+                            self.game_state = GameState::GameOver;
+                        }
+                        let is_running = self.game_state == GameState::GameRun;
+                        if is_running && self.board.get_debtor().is_some() {
+                            self.turn_state = TurnState::StartRaiseFunds;
+                        } else if let Some(prop) = self.take_returned_property() {
+                            self.start_auction(prop);
+                        } else if is_running && self.board.can_roll_again() {
+                            println!("You rolled doubles! Roll again.");
+                            self.turn_state = TurnState::StartWaitingForCommand;
+                        } else {
+                            if self.debug {
+                                print_audit(&self.board);
                             }
                             self.board.end_turn();
                             self.turn_state = TurnState::StartTurn;
-                            self.turn_command = None;
-                        },
-                        
-                        TurnState::ValidatePropIndex => {
-                            let index_str = String::from_utf8(self.key_queue.clone()).unwrap();
-                            if let Ok(index) = index_str.parse::<usize>() {
-                                let player = self.board.get_current_player();
-                                let monopolies = player.borrow().get_monopolies();
-                                if index >= monopolies.len() {
-                                    println!("Index must be within range!");
-                                    self.turn_state = TurnState::StartWaitingForCommand;
-                                    self.turn_command = None;
-                                } else {
-                                    println!("Buy(B) or sell(S)?");
-                                    self.turn_state = TurnState::ConfirmBuySellHouseHotel;
-                                }
-                            } else {
-                                println!("Index must be an integer!");
-                                self.turn_state = TurnState::StartWaitingForCommand;
-                                self.turn_command = None;
-                            }
-                        },
-                        
-                        TurnState::BuyHouseHotel => {
-                            let index_str = String::from_utf8(self.key_queue.clone()).unwrap();
-                            let index = index_str.parse::<usize>().unwrap();
-                            let player = self.board.get_current_player();
-                            let monopolies = player.borrow().get_monopolies();
-                            let prop = monopolies[index].clone();
-                            
-                            let num_houses = {
-                                let prop = prop.borrow();
-                                prop.get_num_houses()
-                            };
-                            let num_hotels = {
-                                let prop = prop.borrow();
-                                prop.get_num_hotels()
-                            };
-                            let cash = {
-                                let player = player.borrow();
-                                player.get_cash()
-                            };
-                            
-                            if num_hotels >= MAX_NUM_HOTELS {
-                                println!("{} cannot be further improved!",
-                                          prop.borrow().get_name());
-                            } else if num_houses >= MAX_NUM_HOUSES {
-                                if cash < HOTEL_COST as i32 {
-                                    println!("You cannot afford another hotel!");
-                                } else {
-                                    println!("Bought a hotel on {}!",
-                                    prop.borrow().get_name());
-                                    for _ in 0..4 {
-                                        prop.borrow_mut().remove_house();
-                                    }
-                                    prop.borrow_mut().add_hotel();
-                                    player.borrow_mut().tax(HOTEL_COST);
-                                }
-                            } else {
-                                if cash < HOUSE_COST as i32 {
-                                    println!("You cannot afford another house!");
-                                } else {
-                                    println!("Bought a house on {}!",
-                                              prop.borrow().get_name());
-                                    prop.borrow_mut().add_house();
-                                    player.borrow_mut().tax(HOUSE_COST);
-                                }          
+                        }
+                        self.turn_command = None;
+                    },
+                    
+                    TurnState::StartRaiseFunds => {
+                        if let Some(debtor) = self.board.get_debtor() {
+                            self.board.settle_debt(debtor.clone());
+                        }
+                        match self.board.get_debtor() {
+                            Some(debtor) => {
+                                let debtor = debtor.borrow();
+                                let owed: Vec<String> = debtor.get_debts().iter()
+                                    .map(|debt| format!("${} to {}", debt.get_amount(),
+                                                        debt.get_creditor_name()))
+                                    .collect();
+                                println!("{} owes {}. Raise funds by selling \
+                                          houses(H) or mortgaging(M), or give up(G).",
+                                         debtor.get_name(), owed.join(" and "));
+                                self.turn_state = TurnState::RaiseFunds;
+                            },
+                            None => self.turn_state = TurnState::AfterCommand,
+                        }
+                    },
+                    TurnState::RaiseFunds => {
+                        // do nothing while waiting
+                    },
+                    TurnState::StartAuctionBid => {
+                        if let Some(auction) = self.board.get_auction() {
+                            let bidder = auction.get_current_bidder().unwrap();
+                            let bidder = bidder.borrow();
+                            match auction.get_high_bidder() {
+                                Some(high_bidder) => println!("The high bid for {} is ${} by {}.",
+                                                              auction.get_lot_name(),
+                                                              auction.get_high_bid(),
+                                                              high_bidder.borrow().get_name()),
+                                None => println!("No one has bid for {} yet.",
+                                                 auction.get_lot_name()),
                             }
+                            println!("{} (${}), enter a bid of at least ${} and press \
+                                      ENTER, or pass(P):",
+                                     bidder.get_name(),
+                                     bidder.get_cash(),
+                                     auction.get_min_bid());
+                        }
+                        self.key_queue = Vec::new();
+                        self.turn_state = TurnState::EnterBid;
+                    },
+                    TurnState::EnterBid => {
+                        // do nothing while waiting
+                    },
+                    TurnState::ValidateBid => {
+                        let bid_str = String::from_utf8(self.key_queue.clone()).unwrap();
+                        if let Ok(bid) = bid_str.parse::<i32>() {
+                            self.place_bid(bid);
+                        } else {
+                            println!("Please enter a valid bid!");
+                            self.turn_state = TurnState::StartAuctionBid;
+                        }
+                    },
+                    TurnState::StartTradeMenu => {
+                        if let Some(ref trade) = self.trade_draft {
+                            trade.print();
+                        }
+                        println!("offer a property(O), ask for a property(W)");
+                        println!("offer cash(C), ask for cash(D)");
+                        println!("offer a Get Out of Jail Free card(J), ask for one(K)");
+                        println!("send the proposal(S), cancel(Q)");
+                        self.turn_state = TurnState::TradeMenu;
+                    },
+                    TurnState::TradeMenu => {
+                        // do nothing while waiting
+                    },
+                    TurnState::EnterTradeValue(_) => {
+                        // do nothing while waiting
+                    },
+                    TurnState::ValidateTradeValue(input) => {
+                        let value_str = String::from_utf8(self.key_queue.clone()).unwrap();
+                        match value_str.parse::<usize>() {
+                            Ok(value) => self.add_trade_value(input, value),
+                            Err(_) => println!("Please enter a number!"),
+                        }
+                        if self.trade_draft.is_some() {
+                            self.turn_state = TurnState::StartTradeMenu;
+                        } else {
                             self.turn_state = TurnState::StartWaitingForCommand;
                             self.turn_command = None;
-                        },
-                        
-                        TurnState::SellHouseHotel => {
-                            let index_str = String::from_utf8(self.key_queue.clone()).unwrap();
-                            let index = index_str.parse::<usize>().unwrap();
-                            let player = self.board.get_current_player();
-                            let monopolies = player.borrow().get_monopolies();
-                            let prop = monopolies[index].clone();
-                            
-                            let num_hotels = {
-                                let prop = prop.borrow();
-                                prop.get_num_hotels()
-                            };
-                            let num_houses = {
-                                let prop = prop.borrow();
-                                prop.get_num_houses()
+                        }
+                    },
+                    TurnState::StartTradeResponse => {
+                        if let Some(trade) = self.board.get_trade() {
+                            println!("{}, accept(Y), reject(N) or counter(C) this trade?",
+                                     trade.get_receiver().borrow().get_name());
+                        }
+                        self.turn_state = TurnState::TradeResponse;
+                    },
+                    TurnState::TradeResponse => {
+                        // do nothing while waiting
+                    },
+                    TurnState::ValidatePropIndex => {
+                        let index_str = String::from_utf8(self.key_queue.clone()).unwrap();
+                        if let Ok(index) = index_str.parse::<usize>() {
+                            let player = self.board.get_acting_player();
+                            let choices = match self.prop_command {
+                                Some(TurnCommand::Mortgage) =>
+                                    player.borrow().get_properties().clone(),
+                                _ => self.board.get_monopolies(&player),
                             };
-                            
-                            if num_hotels >= 1 {
-                                println!("Sold a hotel on {}!", prop.borrow().get_name());
-                                prop.borrow_mut().remove_hotel();
-                                player.borrow_mut().salary(HOTEL_COST / 2);
-                                let new_num_hotels = {
-                                    prop.borrow().get_num_hotels()
-                                };
-                                if new_num_hotels == 0 {
-                                    for _ in 0..4 {
-                                        prop.borrow_mut().add_house();
-                                    }
+                            if index >= choices.len() {
+                                println!("Index must be within range!");
+                                self.after_prop_command();
+                            } else if self.prop_command == Some(TurnCommand::Mortgage) {
+                                let prop = choices[index].clone();
+                                if prop.borrow().is_mortgaged() {
+                                    self.board.unmortgage_property(prop);
+                                } else {
+                                    self.board.mortgage_property(prop);
                                 }
-                            } else if num_houses >= 1 {
-                                println!("Sold a house on {}!", prop.borrow().get_name());
-                                prop.borrow_mut().remove_house();
-                                let player = self.board.get_current_player();
-                                player.borrow_mut().salary(HOUSE_COST / 2);
+                                self.after_prop_command();
                             } else {
-                                println!("No houses to remove on {}!", prop.borrow().get_name());
+                                println!("Buy(B) or sell(S)?");
+                                self.turn_state = TurnState::ConfirmBuySellHouseHotel;
                             }
-                            self.turn_state = TurnState::StartWaitingForCommand;
-                            self.turn_command = None;
-                        },
-                        
-                        _ => (),
-                    };
-                },
-                
-                GameState::GameOver => {
-                    match self.turn_state {
-                        TurnState::ConfirmPlayAgain => (),
-                        _ => {
-                            let winner = self.board.get_winner().unwrap();
-                            println!("{} has won the game!",
-                                winner.borrow().get_name());
-                            println!("{} has ${} and the following assets:",
-                                winner.borrow().get_name(),
-                                winner.borrow().get_cash());
-                            winner.borrow().print_assets();
+                        } else {
+                            println!("Index must be an integer!");
+                            self.after_prop_command();
+                        }
+                    },
                     
-                            println!("Play again?");
-                            self.turn_state = TurnState::ConfirmPlayAgain;
+                    TurnState::BuyHouseHotel => {
+                        let index_str = String::from_utf8(self.key_queue.clone()).unwrap();
+                        let index = index_str.parse::<usize>().unwrap();
+                        let player = self.board.get_acting_player();
+                        let monopolies = self.board.get_monopolies(&player);
+                        self.board.buy_building(monopolies[index].clone());
+                        if self.board.get_auction().is_some() { // housing shortage
+                            self.house_auction = true;
+                            self.after_bid();
+                        } else {
+                            self.after_prop_command();
+                        }
+                    },
                     
-                        },
-                    };
-                },
-            }
-            
-            if let Some(r) = e.render_args() {
-                self.clear(&r);
-                if self.game_state == GameState::GameGUISetup {
-                    self.game_state = GameState::GameStateSetup;
-                } else {
-                    self.board.render(&mut self.gl, &r);
-                }
-            }
+                    TurnState::SellHouseHotel => {
+                        let index_str = String::from_utf8(self.key_queue.clone()).unwrap();
+                        let index = index_str.parse::<usize>().unwrap();
+                        let player = self.board.get_acting_player();
+                        let monopolies = self.board.get_monopolies(&player);
+                        self.board.sell_building(monopolies[index].clone());
+                        self.after_prop_command();
+                    },
+                    
+                    _ => (),
+                };
+            },
             
-            if let Some(Button::Keyboard(key)) = e.press_args() {
-                self.handle_key_input(key);
-            };
+            GameState::GameOver => {
+                match self.turn_state {
+                    TurnState::ConfirmPlayAgain => (),
+                    _ => {
+                        let winner = self.board.get_winner().unwrap();
+                        println!("{} has won the game!",
+                            winner.borrow().get_name());
+                        println!("{} has ${} and the following assets:",
+                            winner.borrow().get_name(),
+                            winner.borrow().get_cash());
+                        winner.borrow().print_assets();
+                
+                        println!("Play again?");
+                        self.turn_state = TurnState::ConfirmPlayAgain;
+                
+                    },
+                };
+            },
         }
     }
 }
@@ -0,0 +1,404 @@
+//
+//! A BoardLayout lists the spaces of a board in order from GO, with
+//! each property's title deed and where each space is drawn, so that
+//! other editions of the board can be played without recompiling.
+//! The standard US board in boards/us.board is built in and used
+//! unless a board file is loaded.
+//!
+//! A board file has one space per line, with `|` between the columns;
+//! blank lines and lines starting with `#` are ignored. See
+//! boards/us.board for the columns.
+//!
+
+use std::rc::Rc;
+use std::cell::RefCell;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use super::property::*;
+use super::space::*;
+
+const US_BOARD: &str = include_str!("../boards/us.board");
+const NUM_COLUMNS: usize = 10;
+
+/// The spaces the Chance cards send players to, named in the card
+/// column of a board file
+pub const CARD_TARGETS: [&str; 4] = ["illinois", "st_charles", "reading", "boardwalk"];
+
+/// The kind of a space, as named in the first column of a board file
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpaceKind {
+    Street(ColorGroup),
+    Railroad,
+    Utility,
+    Go,
+    Chance,
+    CommunityChest,
+    Jail,
+    FreeParking,
+    GoToJail,
+    IncomeTax,
+    LuxuryTax,
+}
+
+/// One line of a board file
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpaceDef {
+    kind: SpaceKind,
+    name: String,
+    deed: Option<Deed>, // only for streets, railroads and utilities
+    x: i32,
+    y: i32,
+    card: Option<String>,
+}
+
+impl SpaceDef {
+    pub fn get_kind(&self) -> &SpaceKind {
+        &self.kind
+    }
+
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    /// A fresh space at the given index, with nobody on it and (for a
+    /// property) no owner
+    pub fn build(&self, index: usize) -> Space {
+        let s_type = match self.kind {
+            SpaceKind::Street(ref color_group) => self.new_prop(color_group.clone()),
+            SpaceKind::Railroad => self.new_prop(ColorGroup::Railroad),
+            SpaceKind::Utility => self.new_prop(ColorGroup::Utility),
+            SpaceKind::Go => SpaceEnum::Go,
+            SpaceKind::Chance => SpaceEnum::Chance,
+            SpaceKind::CommunityChest => SpaceEnum::CommunityChest,
+            SpaceKind::Jail => SpaceEnum::Jail,
+            SpaceKind::FreeParking => SpaceEnum::FreeParking,
+            SpaceKind::GoToJail => SpaceEnum::GoToJail,
+            SpaceKind::IncomeTax => SpaceEnum::IncomeTax,
+            SpaceKind::LuxuryTax => SpaceEnum::LuxuryTax,
+        };
+        Space::new(s_type, self.name.clone(), self.x, self.y, index)
+    }
+
+    fn new_prop(&self, color_group: ColorGroup) -> SpaceEnum {
+        let deed = self.deed.clone().expect("Every property has a deed!");
+        SpaceEnum::Prop(Rc::new(RefCell::new(
+            Property::new(self.name.clone(), deed, color_group))))
+    }
+
+    fn has_card(&self, card: &str) -> bool {
+        self.card.as_deref() == Some(card)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoardLayout {
+    spaces: Vec<SpaceDef>,
+}
+
+impl BoardLayout {
+    /// The standard US board
+    pub fn new() -> BoardLayout {
+        BoardLayout::parse(US_BOARD).expect("the built-in US board is invalid")
+    }
+
+    /// Load a board from a board file
+    pub fn from_file(path: &Path) -> Result<BoardLayout, String> {
+        let mut contents = String::new();
+        File::open(path)
+            .and_then(|mut file| file.read_to_string(&mut contents))
+            .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+        BoardLayout::parse(&contents)
+            .map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Read a board from the contents of a board file
+    pub fn parse(contents: &str) -> Result<BoardLayout, String> {
+        let mut spaces = Vec::new();
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let space = parse_space(line)
+                .map_err(|e| format!("line {}: {}", i + 1, e))?;
+            spaces.push(space);
+        }
+        let layout = BoardLayout { spaces: spaces };
+        layout.validate()?;
+        Ok(layout)
+    }
+
+    pub fn get_spaces(&self) -> &Vec<SpaceDef> {
+        &self.spaces
+    }
+
+    /// The index of the space named in the card column, if any
+    pub fn get_card_target(&self, card: &str) -> Option<usize> {
+        self.spaces.iter().position(|space| space.has_card(card))
+    }
+
+    /// Check the rules that hold for every board: it starts at GO, has
+    /// one jail, has at least one railroad and utility with a rent for
+    /// each one that can be owned, and has every space the Chance cards
+    /// need
+    fn validate(&self) -> Result<(), String> {
+        if self.spaces.first().map(|space| space.kind.clone()) != Some(SpaceKind::Go) {
+            return Err("the first space must be GO".to_string());
+        }
+        if self.count(&SpaceKind::Go) != 1 {
+            return Err("the board must have exactly one GO".to_string());
+        }
+        if self.count(&SpaceKind::Jail) != 1 {
+            return Err("the board must have exactly one jail".to_string());
+        }
+        for &(ref kind, kind_name) in &[(SpaceKind::Railroad, "railroad"),
+                                        (SpaceKind::Utility, "utility")] {
+            let num = self.count(kind);
+            if num == 0 {
+                return Err(format!("the board needs at least one {}, for the Chance \
+                                    card that sends players to the nearest one",
+                                   kind_name));
+            }
+            for space in self.spaces.iter().filter(|space| space.kind == *kind) {
+                let num_rents = space.deed.as_ref().unwrap().get_rents().len();
+                if num_rents != num {
+                    return Err(format!("{} must have {} rents, one for each of \
+                                        the {} like it on the board",
+                                       space.name, num, num));
+                }
+            }
+        }
+        for card in CARD_TARGETS.iter() {
+            let num = self.spaces.iter().filter(|space| space.has_card(card)).count();
+            if num != 1 {
+                return Err(format!("exactly one space must have `{}` in the card \
+                                    column", card));
+            }
+        }
+        Ok(())
+    }
+
+    fn count(&self, kind: &SpaceKind) -> usize {
+        self.spaces.iter().filter(|space| space.kind == *kind).count()
+    }
+}
+
+impl Default for BoardLayout {
+    fn default() -> BoardLayout {
+        BoardLayout::new()
+    }
+}
+
+fn parse_space(line: &str) -> Result<SpaceDef, String> {
+    let columns: Vec<&str> = line.split('|').map(|column| column.trim()).collect();
+    if columns.len() != NUM_COLUMNS && columns.len() != NUM_COLUMNS - 1 {
+        return Err(format!("expected {} columns separated by `|`, found {}",
+                           NUM_COLUMNS, columns.len()));
+    }
+    let name = columns[1].to_string();
+    if name.is_empty() {
+        return Err("every space needs a name".to_string());
+    }
+    let kind = match columns[0] {
+        "street" => SpaceKind::Street(parse_group(columns[2])?),
+        "railroad" => SpaceKind::Railroad,
+        "utility" => SpaceKind::Utility,
+        "go" => SpaceKind::Go,
+        "chance" => SpaceKind::Chance,
+        "community_chest" => SpaceKind::CommunityChest,
+        "jail" => SpaceKind::Jail,
+        "free_parking" => SpaceKind::FreeParking,
+        "go_to_jail" => SpaceKind::GoToJail,
+        "income_tax" => SpaceKind::IncomeTax,
+        "luxury_tax" => SpaceKind::LuxuryTax,
+        kind => return Err(format!("unknown kind of space `{}`", kind)),
+    };
+    let deed = match kind {
+        SpaceKind::Street(_) | SpaceKind::Railroad | SpaceKind::Utility =>
+            Some(parse_deed(&kind, &columns)?),
+        _ => None,
+    };
+    let card = match columns.get(9) {
+        Some(card) if !card.is_empty() => {
+            if !CARD_TARGETS.contains(card) {
+                return Err(format!("unknown card `{}`", card));
+            }
+            if deed.is_none() {
+                return Err(format!("a card can only send players to a property, \
+                                    not {}", name));
+            }
+            Some(card.to_string())
+        },
+        _ => None,
+    };
+    Ok(SpaceDef {
+        kind: kind,
+        name: name,
+        deed: deed,
+        x: parse_number("x", columns[7])?,
+        y: parse_number("y", columns[8])?,
+        card: card,
+    })
+}
+
+fn parse_deed(kind: &SpaceKind, columns: &[&str]) -> Result<Deed, String> {
+    let price = parse_number("price", columns[3])?;
+    let mut rents = Vec::new();
+    for rent in columns[4].split_whitespace() {
+        rents.push(parse_number("rent", rent)?);
+    }
+    let building_cost = match *kind {
+        SpaceKind::Street(_) => {
+            if rents.len() != MAX_NUM_HOUSES as usize + 2 {
+                return Err(format!("a street needs {} rents (0-{} houses and a hotel)",
+                                   MAX_NUM_HOUSES + 2, MAX_NUM_HOUSES));
+            }
+            parse_number("house cost", columns[5])?
+        },
+        _ => {
+            if rents.is_empty() {
+                return Err("railroads and utilities need at least one rent".to_string());
+            }
+            0
+        },
+    };
+    let mortgage_value = parse_number("mortgage", columns[6])?;
+    Ok(Deed::new(price, rents, building_cost, mortgage_value))
+}
+
+fn parse_group(group: &str) -> Result<ColorGroup, String> {
+    match group {
+        "DarkPurple" => Ok(ColorGroup::DarkPurple),
+        "LightBlue" => Ok(ColorGroup::LightBlue),
+        "LightPurple" => Ok(ColorGroup::LightPurple),
+        "Orange" => Ok(ColorGroup::Orange),
+        "Red" => Ok(ColorGroup::Red),
+        "Yellow" => Ok(ColorGroup::Yellow),
+        "Green" => Ok(ColorGroup::Green),
+        "DarkBlue" => Ok(ColorGroup::DarkBlue),
+        _ => Err(format!("unknown color group `{}`", group)),
+    }
+}
+
+fn parse_number(column: &str, value: &str) -> Result<i32, String> {
+    match value.parse::<i32>() {
+        Ok(n) if n >= 0 => Ok(n),
+        _ => Err(format!("{} must be a whole number, not `{}`", column, value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The US board with one piece of text replaced
+    fn us_board_with(from: &str, to: &str) -> String {
+        assert!(US_BOARD.contains(from), "`{}` is not in the US board", from);
+        US_BOARD.replacen(from, to, 1)
+    }
+
+    #[test]
+    fn parses_the_us_board() {
+        let layout = BoardLayout::new();
+        let spaces = layout.get_spaces();
+        assert_eq!(spaces.len(), 40);
+        assert_eq!(*spaces[0].get_kind(), SpaceKind::Go);
+        assert_eq!(*spaces[10].get_kind(), SpaceKind::Jail);
+
+        let boardwalk = &spaces[39];
+        assert_eq!(boardwalk.get_name(), "Boardwalk");
+        assert_eq!(*boardwalk.get_kind(), SpaceKind::Street(ColorGroup::DarkBlue));
+        let deed = boardwalk.deed.as_ref().unwrap();
+        assert_eq!(deed.get_purchase_price(), 400);
+        assert_eq!(*deed.get_rents(), vec![50, 200, 600, 1400, 1700, 2000]);
+        assert_eq!(deed.get_building_cost(), 200);
+        assert_eq!(deed.get_mortgage_value(), 200);
+
+        let reading = spaces[5].deed.as_ref().unwrap();
+        assert_eq!(*reading.get_rents(), vec![25, 50, 100, 200]);
+        assert_eq!(layout.get_card_target("reading"), Some(5));
+        assert_eq!(layout.get_card_target("illinois"), Some(24));
+    }
+
+    #[test]
+    fn wrong_column_count_is_rejected() {
+        let go = US_BOARD.lines().find(|line| line.starts_with("go ")).unwrap();
+        let board = us_board_with(go, "go | GO | 522 | 520");
+        let err = BoardLayout::parse(&board).unwrap_err();
+        assert_eq!(err, "line 23: expected 10 columns separated by `|`, found 4");
+    }
+
+    #[test]
+    fn wrong_number_of_street_rents_is_rejected() {
+        let board = us_board_with("2 10 30 90 160 250", "2 10 30");
+        let err = BoardLayout::parse(&board).unwrap_err();
+        assert_eq!(err, "line 24: a street needs 6 rents (0-4 houses and a hotel)");
+    }
+
+    #[test]
+    fn negative_price_is_rejected() {
+        let board = us_board_with("| DarkPurple  | 60  |", "| DarkPurple  | -60 |");
+        let err = BoardLayout::parse(&board).unwrap_err();
+        assert_eq!(err, "line 24: price must be a whole number, not `-60`");
+    }
+
+    #[test]
+    fn unknown_kind_and_group_are_rejected() {
+        let board = us_board_with("community_chest | Community Chest", "chest | Community Chest");
+        assert_eq!(BoardLayout::parse(&board).unwrap_err(),
+                   "line 25: unknown kind of space `chest`");
+        let board = us_board_with("| DarkPurple  |", "| Purple  |");
+        assert_eq!(BoardLayout::parse(&board).unwrap_err(),
+                   "line 24: unknown color group `Purple`");
+    }
+
+    #[test]
+    fn duplicate_card_target_is_rejected() {
+        let board = us_board_with("| 522 | 375 |", "| 522 | 375 | boardwalk");
+        let err = BoardLayout::parse(&board).unwrap_err();
+        assert_eq!(err, "exactly one space must have `boardwalk` in the card column");
+    }
+
+    #[test]
+    fn missing_card_target_is_rejected() {
+        let board = us_board_with("| 280 | 520 | reading", "| 280 | 520 |");
+        let err = BoardLayout::parse(&board).unwrap_err();
+        assert_eq!(err, "exactly one space must have `reading` in the card column");
+    }
+
+    #[test]
+    fn unknown_card_target_is_rejected() {
+        let board = us_board_with("| reading", "| marylebone");
+        let err = BoardLayout::parse(&board).unwrap_err();
+        assert_eq!(err, "line 28: unknown card `marylebone`");
+    }
+
+    #[test]
+    fn board_must_start_at_go_with_one_jail() {
+        let board = us_board_with("go              | GO", "jail            | GO");
+        assert_eq!(BoardLayout::parse(&board).unwrap_err(), "the first space must be GO");
+        let board = us_board_with("free_parking    | Free Parking", "jail            | Free Parking");
+        assert_eq!(BoardLayout::parse(&board).unwrap_err(),
+                   "the board must have exactly one jail");
+    }
+
+    #[test]
+    fn board_needs_railroads_and_utilities() {
+        let board: String = US_BOARD.lines()
+            .filter(|line| !line.starts_with("utility"))
+            .map(|line| format!("{}\n", line))
+            .collect();
+        let err = BoardLayout::parse(&board).unwrap_err();
+        assert!(err.starts_with("the board needs at least one utility"), "{}", err);
+    }
+
+    #[test]
+    fn railroad_rents_must_match_the_number_of_railroads() {
+        let board = us_board_with("| 200 | 25 50 100 200 ", "| 200 | 25 50 100     ");
+        let err = BoardLayout::parse(&board).unwrap_err();
+        assert_eq!(err, "Reading Railroad must have 4 rents, one for each of the 4 like it \
+                         on the board");
+    }
+}
@@ -0,0 +1,175 @@
+//
+//! The Ledger records every movement of money and deeds in the game:
+//! who paid whom, what changed hands, why, and on which turn. The Bank
+//! writes to it whenever it moves anything, so the ledger is complete
+//! as long as every transfer goes through the Bank.
+//!
+//! A player's statement is every entry they are a party to, with
+//! their cash balance after each payment.
+//!
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use super::player::*;
+use super::property::*;
+
+/// One side of a transaction
+#[derive(Debug, Clone)]
+pub enum Party {
+    Bank,
+    Player(Rc<RefCell<Player>>),
+}
+
+impl Party {
+    pub fn get_name(&self) -> String {
+        match *self {
+            Party::Bank => "the bank".to_string(),
+            Party::Player(ref player) => player.borrow().get_name(),
+        }
+    }
+
+    pub fn is_player(&self, player: &Rc<RefCell<Player>>) -> bool {
+        match *self {
+            Party::Bank => false,
+            Party::Player(ref other) => Rc::ptr_eq(other, player),
+        }
+    }
+}
+
+/// What changed hands
+#[derive(Debug, Clone)]
+pub enum Item {
+    Cash(i32),
+    Deed(Rc<RefCell<Property>>),
+}
+
+impl Item {
+    pub fn get_name(&self) -> String {
+        match *self {
+            Item::Cash(amount) => format!("${}", amount),
+            Item::Deed(ref prop) => prop.borrow().get_name(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Transaction {
+    turn: i32,
+    payer: Party,
+    payee: Party,
+    item: Item,
+    reason: String, // e.g. "rent on Boardwalk" or "GO salary"
+}
+
+impl Transaction {
+    pub fn get_turn(&self) -> i32 {
+        self.turn
+    }
+
+    pub fn get_payer(&self) -> &Party {
+        &self.payer
+    }
+
+    pub fn get_payee(&self) -> &Party {
+        &self.payee
+    }
+
+    pub fn get_item(&self) -> &Item {
+        &self.item
+    }
+
+    pub fn get_reason(&self) -> String {
+        self.reason.clone()
+    }
+
+    /// The change in the player's cash from this transaction
+    pub fn get_cash_change(&self, player: &Rc<RefCell<Player>>) -> i32 {
+        let amount = match self.item {
+            Item::Cash(amount) => amount,
+            Item::Deed(_) => return 0,
+        };
+        let mut change = 0;
+        if self.payee.is_player(player) {
+            change += amount;
+        }
+        if self.payer.is_player(player) {
+            change -= amount;
+        }
+        change
+    }
+}
+
+#[derive(Debug)]
+pub struct Ledger {
+    transactions: Vec<Transaction>,
+    turn: i32, // 0 while the game is being set up
+}
+
+impl Ledger {
+    pub fn new() -> Ledger {
+        Ledger {
+            transactions: Vec::new(),
+            turn: 0,
+        }
+    }
+
+    pub fn get_turn(&self) -> i32 {
+        self.turn
+    }
+
+    /// Every following transaction happens on the next turn
+    pub fn next_turn(&mut self) {
+        self.turn += 1;
+    }
+
+    pub fn record(&mut self, payer: Party, payee: Party, item: Item, reason: &str) {
+        self.transactions.push(Transaction {
+            turn: self.turn,
+            payer: payer,
+            payee: payee,
+            item: item,
+            reason: reason.to_string(),
+        });
+    }
+
+    pub fn get_transactions(&self) -> &Vec<Transaction> {
+        &self.transactions
+    }
+
+    /// Every transaction the player is a party to, oldest first
+    pub fn get_statement(&self, player: &Rc<RefCell<Player>>) -> Vec<&Transaction> {
+        self.transactions.iter()
+            .filter(|t| t.payer.is_player(player) || t.payee.is_player(player))
+            .collect()
+    }
+
+    /// Print the player's statement, one line per transaction, with
+    /// their cash balance after each payment
+    pub fn print_statement(&self, player: &Rc<RefCell<Player>>) {
+        println!("Statement for {}:", player.borrow().get_name());
+        let mut balance = 0;
+        for t in self.get_statement(player) {
+            let (verb, other) = if t.payer.is_player(player) {
+                ("gave", format!("to {}", t.payee.get_name()))
+            } else {
+                ("got", format!("from {}", t.payer.get_name()))
+            };
+            let line = format!("turn {}: {} {} {} for {}", t.turn, verb,
+                               t.item.get_name(), other, t.reason);
+            match t.item {
+                Item::Cash(_) => {
+                    balance += t.get_cash_change(player);
+                    println!("  {:<64} ${}", line, balance);
+                },
+                Item::Deed(_) => println!("  {}", line),
+            }
+        }
+    }
+}
+
+impl Default for Ledger {
+    fn default() -> Ledger {
+        Ledger::new()
+    }
+}
@@ -0,0 +1,31 @@
+//
+//! CIS 198 - Rust Programming
+//! Author: Jack Harkins
+//! Project Name: Rust-Monopoly
+//!
+//! The rules engine (movement, rent, cards, jail, buildings and
+//! bankruptcy) lives in this library and does not need a window,
+//! a GPU or the board image. The Piston frontend is a separate crate
+//! in `gui/` that depends on this one.
+//!
+
+#![allow(dead_code)]
+#![allow(unused_variables)]
+#![allow(unused_must_use)]
+
+extern crate rand;
+
+pub mod board;
+pub mod player;
+pub mod property;
+pub mod cards;
+pub mod game;
+pub mod space;
+pub mod random;
+pub mod auction;
+pub mod trade;
+pub mod rules;
+pub mod layout;
+pub mod bank;
+pub mod ledger;
+pub mod audit;
@@ -1,8 +1,9 @@
 //
 //! Player stores the player's properties, their token color,
-//! the space the player is currently landed on, the last creditor
-//! the player paid rent to, whether the player is in jail,
-//! and whether the player is currently up for his turn.
+//! the space the player is currently landed on, whether the player is
+//! in jail (and for how many turns), the debts the player still has to
+//! raise funds for (and to whom), and whether the player is currently
+//! up for his turn.
 //!
 
 use std::rc::Rc;
@@ -10,8 +11,9 @@ use std::cell::RefCell;
 
 use super::property::*;
 use super::space::*;
+use super::cards::*;
+use super::random::*;
 
-const STARTING_CASH: i32 = 1500;
 pub const PLAYER_WIDTH: i32 = 10;
 
 pub const RED:    [f32; 4] = [1.0, 0.0, 0.0, 1.0];
@@ -20,42 +22,102 @@ pub const YELLOW: [f32; 4] = [1.0, 1.0, 0.0, 1.0];
 pub const GREEN:  [f32; 4] = [0.0, 1.0, 0.0, 1.0];
 pub const BLUE:   [f32; 4] = [0.0, 0.0, 1.0, 1.0];
 pub const PURPLE: [f32; 4] = [102.0/255.0, 0.0, 51.0/255.0, 1.0];
+pub const NUM_TOKEN_COLORS: i32 = 6; // so at most 6 players
+
+/// Changes the rent owed on a property, e.g. after a card moved the player
+#[derive(Debug, Clone, PartialEq)]
+pub enum RentModifier {
+    Normal,
+    Double, // twice the usual rent (nearest railroad card)
+    TimesDice(i32, DiceRoll), // a multiple of a fresh dice throw (nearest utility card)
+}
 
 /// An action the player takes upon landing on a property
 #[derive(Debug, Clone)]
 pub enum LandAction {
-    Rent(Rc<RefCell<Property>>),
+    Rent(Rc<RefCell<Property>>, RentModifier),
     Own(Rc<RefCell<Property>>),
     InsFunds(Rc<RefCell<Property>>),
     MightPurchase(Rc<RefCell<Property>>),
     Space(Rc<RefCell<Space>>),
+    GoToJail, // e.g. after rolling doubles three times in a row
+    Nothing, // e.g. after drawing a card that does not move the player
+}
+
+/// Money a player owes one creditor but could not pay yet
+#[derive(Debug, Clone)]
+pub struct Debt {
+    creditor: Option<Rc<RefCell<Player>>>, // None if the creditor is
+                                           // the bank
+    amount: i32,
+    fee: bool, // a tax, fee or fine owed to the bank, which goes into
+               // the Free Parking jackpot when paid
+}
+
+impl Debt {
+    pub fn get_creditor(&self) -> Option<Rc<RefCell<Player>>> {
+        self.creditor.clone()
+    }
+
+    pub fn get_creditor_name(&self) -> String {
+        match self.creditor {
+            Some(ref creditor) => creditor.borrow().get_name(),
+            None => "the bank".to_string(),
+        }
+    }
+
+    pub fn get_amount(&self) -> i32 {
+        self.amount
+    }
+
+    pub fn is_fee(&self) -> bool {
+        self.fee
+    }
+
+    /// True if both debts are owed to the same creditor for the same
+    /// kind of thing
+    fn is_like(&self, other: &Debt) -> bool {
+        let same_creditor = match (self.creditor.as_ref(), other.creditor.as_ref()) {
+            (Some(owed), Some(other)) => Rc::ptr_eq(owed, other),
+            (None, None) => true,
+            _ => false,
+        };
+        same_creditor && self.fee == other.fee
+    }
 }
 
 #[derive(Debug)]
 pub struct Player {
     name: String,
     cash: i32,
+    debts: Vec<Debt>, // oldest first
+    bankrupt: bool,
     in_jail: bool,
+    jail_turns: i32, // failed attempts to roll doubles in jail
     has_turn: bool,
     space: Rc<RefCell<Space>>,
     properties: Vec<Rc<RefCell<Property>>>,
+    jail_cards: Vec<JailCard>,
     token_color: [f32; 4],
-    creditor: Option<Rc<RefCell<Player>>>, // None if the creditor is
-                                           // the bank
 }
 
 impl Player {
+    /// A player with no cash; the bank pays the starting cash once
+    /// they join the board
     pub fn new(name: String, start_space: Rc<RefCell<Space>>,
                 token_color: [f32; 4]) -> Player {
         Player {
             name: name,
-            cash: STARTING_CASH,
+            cash: 0,
+            debts: Vec::new(),
+            bankrupt: false,
             in_jail: false,
+            jail_turns: 0,
             has_turn: false,
             space: start_space.clone(),
             properties: Vec::new(),
+            jail_cards: Vec::new(),
             token_color: token_color,
-            creditor: None,
         }
     }
     
@@ -63,14 +125,6 @@ impl Player {
         self.token_color
     }
     
-    pub fn set_creditor(&mut self, creditor: Option<Rc<RefCell<Player>>>) {
-        self.creditor = creditor.clone();
-    }
-    
-    pub fn get_creditor(&self) -> Option<Rc<RefCell<Player>>> {
-        self.creditor.clone()
-    }
-    
     pub fn get_property(&self, name: &str) -> Option<Rc<RefCell<Property>>> {
         for prop in &self.properties {
             if name == prop.borrow().get_name() {
@@ -84,72 +138,6 @@ impl Player {
         !(self.get_property(&name.clone()).is_none())
     }
     
-    pub fn has_monopoly(&self, group: ColorGroup) -> bool {
-        let num_in_group = self.get_num_props(&group);
-        match group {
-            ColorGroup::DarkPurple |
-            ColorGroup::DarkBlue  |
-            ColorGroup::Utility   => num_in_group == 2,
-            
-            ColorGroup::LightBlue |
-            ColorGroup::LightPurple  |
-            ColorGroup::Orange  |
-            ColorGroup::Red |
-            ColorGroup::Yellow  |
-            ColorGroup::Green   => num_in_group == 3,
-            
-            ColorGroup::Railroad => num_in_group == 4,
-            
-            _ => unreachable!(),
-        }
-    }
-    
-    /// Get all properties of the player that are in a monopoly
-    /// not counting railroads/utils; can't put houses and hotels on those
-    pub fn get_monopolies(&self) -> Vec<Rc<RefCell<Property>>> {
-        let mut props = Vec::new();
-        if self.has_monopoly(ColorGroup::DarkPurple) {
-            props.push(self.get_property("Mediterranean Avenue").unwrap());
-            props.push(self.get_property("Baltic Avenue").unwrap());
-        }
-        if self.has_monopoly(ColorGroup::LightBlue) {
-            props.push(self.get_property("Oriental Avenue").unwrap());
-            props.push(self.get_property("Vermont Avenue").unwrap());
-            props.push(self.get_property("Connecticut Avenue").unwrap());
-        }
-        if self.has_monopoly(ColorGroup::LightPurple) {
-            props.push(self.get_property("St. Charles Place").unwrap());
-            props.push(self.get_property("States Avenue").unwrap());
-            props.push(self.get_property("Virginia Avenue").unwrap());
-        }
-        if self.has_monopoly(ColorGroup::Orange) {
-            props.push(self.get_property("St. James Place").unwrap());
-            props.push(self.get_property("Tennessee Avenue").unwrap());
-            props.push(self.get_property("New York Avenue").unwrap());
-        }
-        if self.has_monopoly(ColorGroup::Red) {
-            props.push(self.get_property("Kentucky Avenue").unwrap());
-            props.push(self.get_property("Indiana Avenue").unwrap());
-            props.push(self.get_property("Illinois Avenue").unwrap());
-        }
-        if self.has_monopoly(ColorGroup::Yellow) {
-            props.push(self.get_property("Atlantic Avenue").unwrap());
-            props.push(self.get_property("Ventnor Avenue").unwrap());
-            props.push(self.get_property("Marvin Gardens").unwrap());
-        }
-        if self.has_monopoly(ColorGroup::Green) {
-            props.push(self.get_property("Pennsylvania Avenue").unwrap());
-            props.push(self.get_property("North Carolina Avenue").unwrap());
-            props.push(self.get_property("Pacific Avenue").unwrap());
-        }
-        if self.has_monopoly(ColorGroup::DarkBlue) {
-            props.push(self.get_property("Park Place").unwrap());
-            props.push(self.get_property("Boardwalk").unwrap());
-        }
-        
-        props
-    }
-
     pub fn land(&mut self, space: Rc<RefCell<Space>>) -> LandAction {               
         self.space = space.clone();
         match *(space.borrow().get_type()) {
@@ -158,7 +146,7 @@ impl Player {
                     if self.properties.contains(property) {
                         return LandAction::Own(property.clone());
                     }
-                    return LandAction::Rent(property.clone());
+                    return LandAction::Rent(property.clone(), RentModifier::Normal);
                 } else {
                     if self.cash < property.borrow().get_purchase_price() as i32 {
                         return LandAction::InsFunds(property.clone());
@@ -171,23 +159,6 @@ impl Player {
         };
     }
     
-    pub fn has_monopoly_cg(&self, color_group: &ColorGroup) -> bool {
-        let num_props = self.get_num_props(color_group);
-        match *color_group {
-            ColorGroup::DarkPurple => num_props == 2,
-            ColorGroup::LightBlue => num_props == 3,
-            ColorGroup::LightPurple => num_props == 3,
-            ColorGroup::Orange => num_props == 3,
-            ColorGroup::Red => num_props == 3,
-            ColorGroup::Yellow => num_props == 3, 
-            ColorGroup::Green => num_props == 3, 
-            ColorGroup::DarkBlue => num_props == 2,
-            ColorGroup::Railroad => num_props == 4,
-            ColorGroup::Utility => num_props == 2,
-            ColorGroup::Space => unreachable!(),
-        }
-    }
-    
     pub fn get_properties(&self) -> &Vec<Rc<RefCell<Property>>> {
         &self.properties
     }
@@ -196,15 +167,15 @@ impl Player {
         self.properties.push(property.clone());
     }
     
-    pub fn purchase(&mut self, property: Rc<RefCell<Property>>) {
-        println!("{} purchased {} for ${}!",
-                self.name,
-                property.borrow().get_name(),
-                property.borrow().get_purchase_price());
-        self.cash -= property.borrow().get_purchase_price() as i32;
-        self.add_property(property.clone());
+    /// Removes and returns all of the player's properties
+    pub fn take_properties(&mut self) -> Vec<Rc<RefCell<Property>>> {
+        self.properties.drain(..).collect()
     }
-
+    
+    pub fn remove_property(&mut self, property: &Rc<RefCell<Property>>) {
+        self.properties.retain(|prop| prop != property);
+    }
+    
     pub fn salary(&mut self, salary: i32) {
         self.cash += salary as i32;
     }
@@ -226,16 +197,95 @@ impl Player {
     }
 
     pub fn is_bankrupt(&self) -> bool {
-        self.cash <= 0
+        self.bankrupt
+    }
+    
+    /// The player leaves the game; whatever they still owed is written off
+    pub fn declare_bankrupt(&mut self) {
+        self.bankrupt = true;
+        self.debts.clear();
+    }
+    
+    /// Everything the player owes, to all creditors
+    pub fn get_debt(&self) -> i32 {
+        self.debts.iter().map(|debt| debt.amount).sum()
+    }
+    
+    pub fn get_debts(&self) -> &Vec<Debt> {
+        &self.debts
+    }
+    
+    pub fn is_in_debt(&self) -> bool {
+        !self.debts.is_empty()
+    }
+    
+    /// The player owes the creditor (None for the bank) this much more;
+    /// fee is set for taxes, fees and fines owed to the bank
+    pub fn add_debt(&mut self, creditor: Option<Rc<RefCell<Player>>>, amount: i32, fee: bool) {
+        if amount <= 0 {
+            return;
+        }
+        let new_debt = Debt { creditor: creditor, amount: amount, fee: fee };
+        match self.debts.iter_mut().find(|debt| debt.is_like(&new_debt)) {
+            Some(debt) => debt.amount += amount,
+            None => self.debts.push(new_debt),
+        }
+    }
+    
+    /// The player owes this much less on the given debt
+    pub fn reduce_debt(&mut self, paid: &Debt, amount: i32) {
+        if let Some(debt) = self.debts.iter_mut().find(|debt| debt.is_like(paid)) {
+            debt.amount -= amount;
+        }
+        self.debts.retain(|debt| debt.amount > 0);
+    }
+    
+    /// Cash plus the printed price of every property and the cost of
+    /// every building on them, as used for income tax
+    pub fn get_net_worth(&self) -> i32 {
+        let mut worth = self.cash;
+        for prop in &self.properties {
+            let prop = prop.borrow();
+            let num_buildings = prop.get_num_houses() +
+                                prop.get_num_hotels() * (MAX_NUM_HOUSES + 1);
+            worth += prop.get_purchase_price() + num_buildings * prop.get_building_cost();
+        }
+        worth
+    }
+    
+    /// The most cash the player could have by selling all of their
+    /// buildings back to the bank and mortgaging all of their properties
+    pub fn get_liquidation_value(&self) -> i32 {
+        let mut value = self.cash;
+        for prop in &self.properties {
+            let prop = prop.borrow();
+            let num_buildings = prop.get_num_houses() +
+                                prop.get_num_hotels() * (MAX_NUM_HOUSES + 1);
+            value += num_buildings * (prop.get_building_cost() / 2);
+            if !prop.is_mortgaged() {
+                value += prop.get_mortgage_value();
+            }
+        }
+        value
     }
     
     pub fn jail(&mut self, space: Rc<RefCell<Space>>) {
         self.space = space;
         self.in_jail = true;
+        self.jail_turns = 0;
     }
     
     pub fn unjail(&mut self) {
         self.in_jail = false;
+        self.jail_turns = 0;
+    }
+    
+    pub fn get_jail_turns(&self) -> i32 {
+        self.jail_turns
+    }
+    
+    pub fn add_jail_turn(&mut self) {
+        self.jail_turns += 1;
     }
     
     pub fn is_in_jail(&self) -> bool {
@@ -248,14 +298,55 @@ impl Player {
     
     pub fn print_assets(&self) {
         for asset in &(self.properties) {
-            println!("{}", asset.borrow().get_name());
+            let asset = asset.borrow();
+            if asset.is_mortgaged() {
+                println!("{} (mortgaged)", asset.get_name());
+            } else {
+                println!("{}", asset.get_name());
+            }
+        }
+        for card in &(self.jail_cards) {
+            println!("{}", card.get_name());
         }
     }
+    
+    pub fn get_jail_cards(&self) -> &Vec<JailCard> {
+        &self.jail_cards
+    }
+    
+    pub fn has_jail_card(&self) -> bool {
+        !self.jail_cards.is_empty()
+    }
+    
+    pub fn add_jail_card(&mut self, card: JailCard) {
+        self.jail_cards.push(card);
+    }
+    
+    /// Removes one of the player's Get Out of Jail Free cards, if any
+    pub fn take_jail_card(&mut self) -> Option<JailCard> {
+        self.jail_cards.pop()
+    }
 
-    pub fn collect_rent(&mut self, other: Rc<RefCell<Player>>, 
-                        rent: i32) {
-        self.cash += rent as i32;
-        other.borrow_mut().cash -= rent as i32;
+    /// Removes a Get Out of Jail Free card from the given deck, if the
+    /// player has one
+    pub fn remove_jail_card(&mut self, card: JailCard) -> bool {
+        match self.jail_cards.iter().position(|c| *c == card) {
+            Some(index) => {
+                self.jail_cards.remove(index);
+                true
+            },
+            None => false,
+        }
+    }
+
+    /// Total number of houses on all of the player's properties
+    pub fn get_num_houses(&self) -> i32 {
+        self.properties.iter().map(|prop| prop.borrow().get_num_houses()).sum()
+    }
+    
+    /// Total number of hotels on all of the player's properties
+    pub fn get_num_hotels(&self) -> i32 {
+        self.properties.iter().map(|prop| prop.borrow().get_num_hotels()).sum()
     }
     
     pub fn get_num_props(&self, color_group: &ColorGroup) -> i32 {
@@ -1,22 +1,18 @@
 //
 //! Contains the majority of the state associated with owning
 //! a property, such as its name, the number of houses and hotels
-//! held on it, its base rent, and the color group it belongs to.
+//! held on it, its title deed, and the color group it belongs to.
 //!
 
 use std::rc::Rc;
 use std::cell::RefCell;
-use piston::input::*;
-use opengl_graphics::GlGraphics;
 
 use super::player::*;
-use super::game::*;
 
 pub const MAX_NUM_HOUSES: i32 = 4;
 pub const MAX_NUM_HOTELS: i32 = 1;
-
-pub const HOUSE_COST: i32 = 80;
-pub const HOTEL_COST: i32 = 160;
+pub const NUM_BANK_HOUSES: i32 = 32; // all the houses in the game
+pub const NUM_BANK_HOTELS: i32 = 12; // all the hotels in the game
 
 /// ColorGroups are used to determine if
 /// a player holds a monopoly.
@@ -35,13 +31,59 @@ pub enum ColorGroup {
     Space
 }
 
+/// The title deed of a property, as printed on the card.
+///
+/// The rent schedule depends on the kind of property: for a street it
+/// is the rent with 0-4 houses and then with a hotel, for a railroad
+/// it is the rent by the number of railroads owned (1-4), and for a
+/// utility it is the dice multiplier by the number of utilities owned.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Deed {
+    purchase_price: i32,
+    rents: Vec<i32>,
+    building_cost: i32, // per house or hotel; 0 if nothing can be built
+    mortgage_value: i32,
+}
+
+impl Deed {
+    pub fn new(purchase_price: i32, rents: Vec<i32>, building_cost: i32,
+               mortgage_value: i32) -> Deed {
+        Deed {
+            purchase_price: purchase_price,
+            rents: rents,
+            building_cost: building_cost,
+            mortgage_value: mortgage_value,
+        }
+    }
+    
+    pub fn get_purchase_price(&self) -> i32 {
+        self.purchase_price
+    }
+    
+    /// Entry `index` of the rent schedule (see Deed)
+    pub fn get_rent(&self, index: usize) -> i32 {
+        self.rents[index]
+    }
+    
+    pub fn get_rents(&self) -> &Vec<i32> {
+        &self.rents
+    }
+    
+    pub fn get_building_cost(&self) -> i32 {
+        self.building_cost
+    }
+    
+    pub fn get_mortgage_value(&self) -> i32 {
+        self.mortgage_value
+    }
+}
+
 /// Represents a property by which players can collect rent.
 /// Can be bought, sold, and traded (eventually).
 #[derive(Debug)]
 pub struct Property {
     name: String,
-    purchase_price: i32,
-    base_rent: i32,
+    deed: Deed,
     color_group: ColorGroup,
     owner: Option<Rc<RefCell<Player>>>,
     is_mortgaged: bool,
@@ -50,12 +92,10 @@ pub struct Property {
 }
 
 impl Property {
-    pub fn new(name: String, purchase_price: i32, base_rent: i32,
-               color_group: ColorGroup) -> Self {
+    pub fn new(name: String, deed: Deed, color_group: ColorGroup) -> Self {
         Property {
             name: name,
-            purchase_price: purchase_price,
-            base_rent: base_rent,
+            deed: deed,
             color_group: color_group,
             owner: None,
             is_mortgaged: false,
@@ -80,6 +120,12 @@ impl Property {
         self.num_hotels > 0
     }
 
+    /// How far the property is built up: its number of houses, with a
+    /// hotel counting as one more than the most houses
+    pub fn get_building_level(&self) -> i32 {
+        self.num_houses + self.num_hotels * (MAX_NUM_HOUSES + 1)
+    }
+
     pub fn get_name(&self) -> String {
         self.name.clone()
     }
@@ -88,8 +134,31 @@ impl Property {
         self.is_mortgaged
     }
 
+    pub fn get_deed(&self) -> &Deed {
+        &self.deed
+    }
+    
+    /// Rent without any houses, hotels or monopoly
     pub fn get_base_rent(&self) -> i32 {
-        self.base_rent
+        self.deed.get_rent(0)
+    }
+    
+    pub fn get_building_cost(&self) -> i32 {
+        self.deed.get_building_cost()
+    }
+    
+    pub fn get_mortgage_value(&self) -> i32 {
+        self.deed.get_mortgage_value()
+    }
+    
+    /// Lifting a mortgage costs the mortgage value plus 10% interest
+    pub fn get_unmortgage_cost(&self) -> i32 {
+        let value = self.get_mortgage_value();
+        value + (value + 9) / 10
+    }
+    
+    pub fn set_mortgaged(&mut self, is_mortgaged: bool) {
+        self.is_mortgaged = is_mortgaged;
     }
     
     pub fn get_color_group(&self) -> ColorGroup {
@@ -108,7 +177,7 @@ impl Property {
     }
 
     pub fn get_purchase_price(&self) -> i32 {
-        self.purchase_price
+        self.deed.get_purchase_price()
     }
 
     pub fn remove_house(&mut self) {
@@ -132,12 +201,6 @@ impl Property {
     }
 }
 
-impl Render for Property {
-    fn render(&self, gl: &mut GlGraphics, args: &RenderArgs) {
-        println!("Drew a property");
-    }
-}
-
 impl PartialEq for Property {
     fn eq(&self, other: &Property) -> bool {
         self.name == other.name
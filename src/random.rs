@@ -0,0 +1,177 @@
+//
+//! Random is the single source of randomness for a game: dice rolls,
+//! card shuffles and the turn-order roll all go through the one owned
+//! by the Board.
+//!
+//! Every Random is seeded, and the seed is printed at the start of a
+//! game so that it can be replayed exactly. For tests, dice can be
+//! loaded and card decks stacked; loaded values are used first, and
+//! the seeded generator takes over once they run out.
+//!
+
+use std::collections::VecDeque;
+use rand::{self, Rng, SeedableRng, StdRng};
+
+use super::cards::*;
+
+/// The result of rolling both dice. Both values are kept so that
+/// doubles can be detected.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiceRoll {
+    first: i32,
+    second: i32,
+}
+
+impl DiceRoll {
+    pub fn new(first: i32, second: i32) -> DiceRoll {
+        DiceRoll {
+            first: first,
+            second: second,
+        }
+    }
+
+    pub fn get_first(&self) -> i32 {
+        self.first
+    }
+
+    pub fn get_second(&self) -> i32 {
+        self.second
+    }
+
+    pub fn get_total(&self) -> i32 {
+        self.first + self.second
+    }
+
+    pub fn is_doubles(&self) -> bool {
+        self.first == self.second
+    }
+}
+
+pub struct Random {
+    seed: usize,
+    rng: StdRng,
+    loaded_dice: VecDeque<i32>,
+    stacked_chance: VecDeque<Chance>,
+    stacked_comm_chest: VecDeque<CommunityChest>,
+}
+
+impl Random {
+    /// A Random with a fresh seed
+    pub fn new() -> Random {
+        let seed = rand::thread_rng().gen::<usize>();
+        Random::from_seed(seed)
+    }
+
+    /// A Random that replays the game played with the given seed
+    pub fn from_seed(seed: usize) -> Random {
+        let seed_slice: &[usize] = &[seed];
+        Random {
+            seed: seed,
+            rng: SeedableRng::from_seed(seed_slice),
+            loaded_dice: VecDeque::new(),
+            stacked_chance: VecDeque::new(),
+            stacked_comm_chest: VecDeque::new(),
+        }
+    }
+
+    pub fn get_seed(&self) -> usize {
+        self.seed
+    }
+
+    /// Load the dice: each value is the result of one die, in the
+    /// order they will be rolled (so a roll of 7 is e.g. [3, 4])
+    pub fn load_dice(&mut self, faces: &[i32]) {
+        for face in faces {
+            assert!(*face >= 1 && *face <= 6, "a die only has faces 1-6");
+            self.loaded_dice.push_back(*face);
+        }
+    }
+
+    /// Stack the Chance deck: these cards are drawn next, in order
+    pub fn stack_chance(&mut self, cards: Vec<Chance>) {
+        self.stacked_chance.extend(cards);
+    }
+
+    /// Stack the Community Chest deck: these cards are drawn next, in order
+    pub fn stack_comm_chest(&mut self, cards: Vec<CommunityChest>) {
+        self.stacked_comm_chest.extend(cards);
+    }
+
+    pub fn next_stacked_chance(&mut self) -> Option<Chance> {
+        self.stacked_chance.pop_front()
+    }
+
+    pub fn next_stacked_comm_chest(&mut self) -> Option<CommunityChest> {
+        self.stacked_comm_chest.pop_front()
+    }
+
+    /// Roll a single die: return an integer between 1 and 6, inclusive
+    pub fn roll_die(&mut self) -> i32 {
+        match self.loaded_dice.pop_front() {
+            Some(face) => face,
+            None => self.rng.gen_range(1, 7), // [1, 7)
+        }
+    }
+
+    /// Simulate a roll of both dice; the total is between 2 and 12, inclusive
+    pub fn roll_dice(&mut self) -> DiceRoll {
+        let first = self.roll_die();
+        let second = self.roll_die();
+        DiceRoll::new(first, second)
+    }
+
+    /// Put the cards in a random order
+    pub fn shuffle<T>(&mut self, cards: &mut [T]) {
+        self.rng.shuffle(cards);
+    }
+}
+
+impl Default for Random {
+    fn default() -> Random {
+        Random::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_gives_same_rolls() {
+        let mut first = Random::from_seed(42);
+        let mut second = Random::from_seed(42);
+        for _ in 0..20 {
+            assert_eq!(first.roll_dice(), second.roll_dice());
+        }
+    }
+
+    #[test]
+    fn loaded_dice_come_before_the_seeded_rolls() {
+        let mut loaded = Random::from_seed(42);
+        let mut seeded = Random::from_seed(42);
+        loaded.load_dice(&[3, 4, 6, 6]);
+        assert_eq!(loaded.roll_dice(), DiceRoll::new(3, 4));
+        assert!(loaded.roll_dice().is_doubles());
+        for _ in 0..10 {
+            assert_eq!(loaded.roll_dice(), seeded.roll_dice());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn dice_cannot_be_loaded_with_impossible_faces() {
+        Random::from_seed(42).load_dice(&[7]);
+    }
+
+    #[test]
+    fn stacked_cards_come_out_in_order() {
+        let mut random = Random::from_seed(42);
+        random.stack_chance(vec![Chance::AdvanceToBoardwalk, Chance::AdvanceToGo]);
+        random.stack_comm_chest(vec![CommunityChest::GoToJail]);
+        assert_eq!(random.next_stacked_chance(), Some(Chance::AdvanceToBoardwalk));
+        assert_eq!(random.next_stacked_chance(), Some(Chance::AdvanceToGo));
+        assert_eq!(random.next_stacked_chance(), None);
+        assert_eq!(random.next_stacked_comm_chest(), Some(CommunityChest::GoToJail));
+        assert_eq!(random.next_stacked_comm_chest(), None);
+    }
+}
@@ -0,0 +1,244 @@
+//
+//! A RuleSet holds the amounts and house rules a game is played with,
+//! so that every table can play by its own rules. The standard rules
+//! are used unless a rules file is loaded.
+//!
+//! A rules file has one `key = value` pair per line; blank lines and
+//! lines starting with `#` are ignored, and any key left out keeps its
+//! standard value. See rules.cfg in the project root for every key.
+//!
+
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+use super::player::*;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleSet {
+    starting_cash: i32,
+    go_salary: i32,
+    income_tax_amt: i32,
+    income_tax_percent: i32, // of net worth, instead of income_tax_amt
+    luxury_tax_amt: i32,
+    jail_fine: i32,
+    max_num_players: i32,
+    free_parking_jackpot: bool, // fees and taxes go to whoever lands
+                                // on Free Parking
+    free_parking_seed: i32, // put in the jackpot by the bank each time
+                            // it is emptied
+    double_go_salary: bool, // landing exactly on GO pays twice the salary
+    auctions: bool, // unbought properties are auctioned
+    even_building: bool, // houses are built and sold evenly across
+                         // a color group
+    no_rent_in_jail: bool, // owners in jail collect no rent
+}
+
+impl RuleSet {
+    /// The standard rules
+    pub fn new() -> RuleSet {
+        RuleSet {
+            starting_cash: 1500,
+            go_salary: 200,
+            income_tax_amt: 200,
+            income_tax_percent: 10,
+            luxury_tax_amt: 75,
+            jail_fine: 50,
+            max_num_players: 6,
+            free_parking_jackpot: false,
+            free_parking_seed: 0,
+            double_go_salary: false,
+            auctions: true,
+            even_building: true,
+            no_rent_in_jail: false,
+        }
+    }
+
+    /// Load the rules from a rules file
+    pub fn from_file(path: &Path) -> Result<RuleSet, String> {
+        let mut contents = String::new();
+        File::open(path)
+            .and_then(|mut file| file.read_to_string(&mut contents))
+            .map_err(|e| format!("Could not read {}: {}", path.display(), e))?;
+        RuleSet::parse(&contents)
+            .map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Read the rules from the contents of a rules file, starting from
+    /// the standard rules
+    pub fn parse(contents: &str) -> Result<RuleSet, String> {
+        let mut rules = RuleSet::new();
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut parts = line.splitn(2, '=');
+            let key = parts.next().unwrap().trim();
+            let value = match parts.next() {
+                Some(value) => value.trim(),
+                None => return Err(format!("line {}: expected `key = value`", i + 1)),
+            };
+            rules.set(key, value)
+                .map_err(|e| format!("line {}: {}", i + 1, e))?;
+        }
+        Ok(rules)
+    }
+
+    fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "starting_cash" => self.starting_cash = parse_amount(key, value)?,
+            "go_salary" => self.go_salary = parse_amount(key, value)?,
+            "income_tax_amt" => self.income_tax_amt = parse_amount(key, value)?,
+            "income_tax_percent" => self.income_tax_percent = parse_amount(key, value)?,
+            "luxury_tax_amt" => self.luxury_tax_amt = parse_amount(key, value)?,
+            "jail_fine" => self.jail_fine = parse_amount(key, value)?,
+            "max_num_players" => {
+                self.max_num_players = parse_amount(key, value)?;
+                if self.max_num_players < 2 {
+                    return Err("max_num_players must be at least 2".to_string());
+                }
+                if self.max_num_players > NUM_TOKEN_COLORS {
+                    return Err(format!("max_num_players can be at most {}, one for \
+                                        each token color", NUM_TOKEN_COLORS));
+                }
+            },
+            "free_parking_jackpot" => self.free_parking_jackpot = parse_flag(key, value)?,
+            "free_parking_seed" => self.free_parking_seed = parse_amount(key, value)?,
+            "double_go_salary" => self.double_go_salary = parse_flag(key, value)?,
+            "auctions" => self.auctions = parse_flag(key, value)?,
+            "even_building" => self.even_building = parse_flag(key, value)?,
+            "no_rent_in_jail" => self.no_rent_in_jail = parse_flag(key, value)?,
+            _ => return Err(format!("unknown rule `{}`", key)),
+        }
+        Ok(())
+    }
+
+    pub fn get_starting_cash(&self) -> i32 {
+        self.starting_cash
+    }
+
+    pub fn get_go_salary(&self) -> i32 {
+        self.go_salary
+    }
+
+    pub fn get_income_tax_amt(&self) -> i32 {
+        self.income_tax_amt
+    }
+
+    pub fn get_income_tax_percent(&self) -> i32 {
+        self.income_tax_percent
+    }
+
+    pub fn get_luxury_tax_amt(&self) -> i32 {
+        self.luxury_tax_amt
+    }
+
+    pub fn get_jail_fine(&self) -> i32 {
+        self.jail_fine
+    }
+
+    pub fn get_max_num_players(&self) -> i32 {
+        self.max_num_players
+    }
+
+    pub fn has_free_parking_jackpot(&self) -> bool {
+        self.free_parking_jackpot
+    }
+
+    pub fn get_free_parking_seed(&self) -> i32 {
+        self.free_parking_seed
+    }
+
+    pub fn has_double_go_salary(&self) -> bool {
+        self.double_go_salary
+    }
+
+    pub fn has_auctions(&self) -> bool {
+        self.auctions
+    }
+
+    pub fn has_even_building(&self) -> bool {
+        self.even_building
+    }
+
+    pub fn has_no_rent_in_jail(&self) -> bool {
+        self.no_rent_in_jail
+    }
+}
+
+impl Default for RuleSet {
+    fn default() -> RuleSet {
+        RuleSet::new()
+    }
+}
+
+fn parse_amount(key: &str, value: &str) -> Result<i32, String> {
+    match value.parse::<i32>() {
+        Ok(n) if n >= 0 => Ok(n),
+        _ => Err(format!("`{}` must be a whole number of dollars, not `{}`", key, value)),
+    }
+}
+
+fn parse_flag(key: &str, value: &str) -> Result<bool, String> {
+    match value {
+        "true" | "yes" | "on" => Ok(true),
+        "false" | "no" | "off" => Ok(false),
+        _ => Err(format!("`{}` must be true or false, not `{}`", key, value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_file_gives_standard_rules() {
+        assert_eq!(RuleSet::parse("").unwrap(), RuleSet::new());
+        assert_eq!(RuleSet::parse("# only a comment\n\n").unwrap(), RuleSet::new());
+    }
+
+    #[test]
+    fn keys_override_standard_rules() {
+        let rules = RuleSet::parse("starting_cash = 2000\n\
+                                    # a comment\n\
+                                    auctions = off\n\
+                                    free_parking_jackpot = yes\n").unwrap();
+        assert_eq!(rules.get_starting_cash(), 2000);
+        assert!(!rules.has_auctions());
+        assert!(rules.has_free_parking_jackpot());
+        assert_eq!(rules.get_go_salary(), RuleSet::new().get_go_salary());
+    }
+
+    #[test]
+    fn unknown_key_is_rejected() {
+        let err = RuleSet::parse("go_salary = 200\nsalary = 100").unwrap_err();
+        assert_eq!(err, "line 2: unknown rule `salary`");
+    }
+
+    #[test]
+    fn missing_equals_is_rejected() {
+        let err = RuleSet::parse("\njail_fine 50").unwrap_err();
+        assert_eq!(err, "line 2: expected `key = value`");
+    }
+
+    #[test]
+    fn negative_amount_is_rejected() {
+        let err = RuleSet::parse("jail_fine = -50").unwrap_err();
+        assert!(err.starts_with("line 1: `jail_fine` must be a whole number"), "{}", err);
+    }
+
+    #[test]
+    fn bad_flag_is_rejected() {
+        let err = RuleSet::parse("auctions = maybe").unwrap_err();
+        assert_eq!(err, "line 1: `auctions` must be true or false, not `maybe`");
+    }
+
+    #[test]
+    fn max_num_players_is_bounded() {
+        assert!(RuleSet::parse("max_num_players = 1").is_err());
+        assert!(RuleSet::parse("max_num_players = 7").is_err());
+        let rules = RuleSet::parse("max_num_players = 2").unwrap();
+        assert_eq!(rules.get_max_num_players(), 2);
+    }
+}
@@ -0,0 +1,151 @@
+//
+//! A Trade is a proposal from one player to another. Each side of the
+//! trade may bundle properties, cash and Get Out of Jail Free cards.
+//! The player receiving the proposal can accept it, reject it, or
+//! counter it with a proposal of their own.
+//!
+//! The Trade itself only records what is on the table; the Board
+//! checks that the trade is allowed and moves the assets once it is
+//! accepted.
+//!
+
+use std::rc::Rc;
+use std::cell::RefCell;
+
+use super::player::*;
+use super::property::*;
+use super::cards::*;
+
+/// What one player puts into a trade
+#[derive(Debug, Clone)]
+pub struct TradeSide {
+    properties: Vec<Rc<RefCell<Property>>>,
+    cash: i32,
+    jail_cards: Vec<JailCard>,
+}
+
+impl TradeSide {
+    pub fn new() -> TradeSide {
+        TradeSide {
+            properties: Vec::new(),
+            cash: 0,
+            jail_cards: Vec::new(),
+        }
+    }
+
+    pub fn get_properties(&self) -> &Vec<Rc<RefCell<Property>>> {
+        &self.properties
+    }
+
+    pub fn get_cash(&self) -> i32 {
+        self.cash
+    }
+
+    pub fn get_jail_cards(&self) -> &Vec<JailCard> {
+        &self.jail_cards
+    }
+
+    /// Adds the property, unless it is already part of this side
+    pub fn add_property(&mut self, property: Rc<RefCell<Property>>) {
+        if !self.properties.contains(&property) {
+            self.properties.push(property);
+        }
+    }
+
+    pub fn set_cash(&mut self, cash: i32) {
+        self.cash = cash;
+    }
+
+    pub fn add_jail_card(&mut self, card: JailCard) {
+        self.jail_cards.push(card);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.properties.is_empty() && self.cash == 0 && self.jail_cards.is_empty()
+    }
+
+    /// Print each item on this side of the trade, one per line
+    pub fn print(&self) {
+        if self.is_empty() {
+            println!("    nothing");
+        }
+        for prop in &self.properties {
+            println!("    {}", prop.borrow().get_name());
+        }
+        if self.cash > 0 {
+            println!("    ${}", self.cash);
+        }
+        for card in &self.jail_cards {
+            println!("    {}", card.get_name());
+        }
+    }
+}
+
+impl Default for TradeSide {
+    fn default() -> TradeSide {
+        TradeSide::new()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Trade {
+    proposer: Rc<RefCell<Player>>,
+    receiver: Rc<RefCell<Player>>,
+    offered: TradeSide, // given by the proposer
+    requested: TradeSide, // given by the receiver
+}
+
+impl Trade {
+    /// An empty trade proposed by one player to another
+    pub fn new(proposer: Rc<RefCell<Player>>,
+               receiver: Rc<RefCell<Player>>) -> Trade {
+        Trade {
+            proposer: proposer,
+            receiver: receiver,
+            offered: TradeSide::new(),
+            requested: TradeSide::new(),
+        }
+    }
+
+    pub fn get_proposer(&self) -> Rc<RefCell<Player>> {
+        self.proposer.clone()
+    }
+
+    pub fn get_receiver(&self) -> Rc<RefCell<Player>> {
+        self.receiver.clone()
+    }
+
+    pub fn get_offered(&self) -> &TradeSide {
+        &self.offered
+    }
+
+    pub fn get_offered_mut(&mut self) -> &mut TradeSide {
+        &mut self.offered
+    }
+
+    pub fn get_requested(&self) -> &TradeSide {
+        &self.requested
+    }
+
+    pub fn get_requested_mut(&mut self) -> &mut TradeSide {
+        &mut self.requested
+    }
+
+    /// The same trade seen from the receiver's side, as the starting
+    /// point for a counter-proposal
+    pub fn reversed(&self) -> Trade {
+        Trade {
+            proposer: self.receiver.clone(),
+            receiver: self.proposer.clone(),
+            offered: self.requested.clone(),
+            requested: self.offered.clone(),
+        }
+    }
+
+    pub fn print(&self) {
+        println!("{} offers:", self.proposer.borrow().get_name());
+        self.offered.print();
+        println!("in exchange for {}'s:", self.receiver.borrow().get_name());
+        self.requested.print();
+    }
+}